@@ -0,0 +1,75 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+/// The order in which the bits of packed items are laid out in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// Items fill each byte starting from its most significant bit, and the most significant bit of an item comes first. This is
+    /// the layout used by [`PackedIntegerArray`].
+    MsbFirst,
+    /// Items fill each byte starting from its least significant bit, and the least significant bit of an item comes first.
+    LsbFirst
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Rewrites the packed content, which is assumed to be laid out in the `from` order, into the `to` order.
+    ///
+    /// Packed data produced with the opposite convention can be adopted by copying it in through [`AsMut`] and converting it from
+    /// [`BitOrder::LsbFirst`] to [`BitOrder::MsbFirst`], and handed back by converting the other way.
+    ///
+    /// ```rust
+    /// use pia::BitOrder;
+    ///
+    /// // Items 1, 2, 3, 4 of 4 bits each packed least significant bit first
+    /// let mut packed_array = pia::PackedIntegerArray::<4, 4>::new();
+    /// packed_array.as_mut().copy_from_slice(&[0x21, 0x43]);
+    ///
+    /// packed_array.convert_order(BitOrder::LsbFirst, BitOrder::MsbFirst);
+    /// assert_eq!(packed_array, [1, 2, 3, 4]);
+    ///
+    /// packed_array.convert_order(BitOrder::MsbFirst, BitOrder::LsbFirst);
+    /// assert_eq!(packed_array.as_ref(), [0x21, 0x43]);
+    /// ```
+    ///
+    /// Note: while the content is in [`BitOrder::LsbFirst`] order the other methods of the array do not read or write the items
+    /// correctly.
+    pub fn convert_order(&mut self, from: BitOrder, to: BitOrder) {
+        if from == to {
+            return;
+        }
+
+        let mut converted = [0; get_array_length(BITS, LEN)];
+        for i in 0..LEN {
+            let bit = i * (BITS as usize);
+            let value = match from {
+                BitOrder::MsbFirst => self.get(i),
+                BitOrder::LsbFirst => {
+                    let start_byte = bit / (u8::BITS as usize);
+                    let mut window = self.content[start_byte] as u16;
+                    if let Some(&next) = self.content.get(start_byte + 1) {
+                        window |= (next as u16) << (u8::BITS as usize);
+                    }
+                    ((window >> (bit % (u8::BITS as usize))) & ((1u16 << BITS) - 1)) as u8
+                }
+            };
+
+            match to {
+                BitOrder::MsbFirst => {
+                    let window = (value as u16) << ((u8::BITS as usize * 2) - (bit % (u8::BITS as usize)) - (BITS as usize));
+                    converted[bit / (u8::BITS as usize)] |= (window >> (u8::BITS as usize)) as u8;
+                    if let Some(next) = converted.get_mut(bit / (u8::BITS as usize) + 1) {
+                        *next |= window as u8;
+                    }
+                },
+                BitOrder::LsbFirst => {
+                    let window = (value as u16) << (bit % (u8::BITS as usize));
+                    converted[bit / (u8::BITS as usize)] |= window as u8;
+                    if let Some(next) = converted.get_mut(bit / (u8::BITS as usize) + 1) {
+                        *next |= (window >> (u8::BITS as usize)) as u8;
+                    }
+                }
+            }
+        }
+        self.content = converted;
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+/// Transposes an 8 by 8 matrix of bits, where row `r` is `rows[r]` and column `c` is bit `7 - c` of every row, so that the most
+/// significant bit comes first just like the items of a [`PackedIntegerArray`].
+///
+/// The whole matrix is transposed in a `u64` with three rounds of swapping bit blocks, instead of moving the bits one at a time.
+///
+/// ```rust
+/// let rows = [
+///     0b10000000,
+///     0b11000000,
+///     0b11100000,
+///     0b11110000,
+///     0b11111000,
+///     0b11111100,
+///     0b11111110,
+///     0b11111111
+/// ];
+///
+/// assert_eq!(pia::transpose8x8(rows), [
+///     0b11111111,
+///     0b01111111,
+///     0b00111111,
+///     0b00011111,
+///     0b00001111,
+///     0b00000111,
+///     0b00000011,
+///     0b00000001
+/// ]);
+/// ```
+pub const fn transpose8x8(rows: [u8; 8]) -> [u8; 8] {
+    let mut x = u64::from_be_bytes(rows);
+
+    // Swap the off diagonal bits of every 2 by 2 block, then of every 4 by 4 block of 2 by 2 blocks, then of the 8 by 8 block
+    let mut t = (x ^ (x >> 7)) & 0x00AA00AA00AA00AA;
+    x = x ^ t ^ (t << 7);
+    t = (x ^ (x >> 14)) & 0x0000CCCC0000CCCC;
+    x = x ^ t ^ (t << 14);
+    t = (x ^ (x >> 28)) & 0x00000000F0F0F0F0;
+    x = x ^ t ^ (t << 28);
+
+    x.to_be_bytes()
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Splits the array into `BITS` bitplanes, where bitplane `b` holds bit `b` of every item, starting from the least significant
+    /// bit.
+    ///
+    /// Bitplanes can be compressed or combined with boolean operations one plane at a time. Every group of 8 items is split with a
+    /// single [`transpose8x8()`], which yields one packed byte of every plane.
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<2, 4>::from_unpacked([0, 1, 2, 3]);
+    /// let [low, high] = packed_array.to_bitplanes();
+    ///
+    /// assert_eq!(low, [0, 1, 0, 1]);
+    /// assert_eq!(high, [0, 0, 1, 1]);
+    /// ```
+    pub fn to_bitplanes(&self) -> [PackedIntegerArray<1, LEN>; BITS as usize]
+    where [u8; get_array_length(1, LEN)]: Sized {
+        let mut planes = [(); BITS as usize].map(|_| PackedIntegerArray::new());
+        for group in 0..get_array_length(1, LEN) {
+            let mut rows = [0; 8];
+            for (row, i) in (group * 8..usize::min(group * 8 + 8, LEN)).enumerate() {
+                rows[row] = self.get(i);
+            }
+
+            // Column `7 - b` of the items holds bit `b` of every item
+            let columns = transpose8x8(rows);
+            for (bit, plane) in planes.iter_mut().enumerate() {
+                plane.content[group] = columns[7 - bit];
+            }
+        }
+        planes
+    }
+
+    /// Recomposes an array from its bitplanes, the inverse of [`PackedIntegerArray::to_bitplanes()`].
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 9>::from_unpacked([0, 1, 2, 3, 4, 5, 6, 7, 0]);
+    /// let planes = packed_array.to_bitplanes();
+    ///
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::from_bitplanes(&planes), packed_array);
+    /// ```
+    pub fn from_bitplanes(planes: &[PackedIntegerArray<1, LEN>; BITS as usize]) -> Self
+    where [u8; get_array_length(1, LEN)]: Sized {
+        let mut array = Self::new();
+        for group in 0..get_array_length(1, LEN) {
+            let mut columns = [0; 8];
+            for (bit, plane) in planes.iter().enumerate() {
+                columns[7 - bit] = plane.content[group];
+            }
+
+            let rows = transpose8x8(columns);
+            for (row, i) in (group * 8..usize::min(group * 8 + 8, LEN)).enumerate() {
+                array.set(i, rows[row]);
+            }
+        }
+        array
+    }
+}
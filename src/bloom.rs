@@ -0,0 +1,131 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use crate::{get_array_length, PackedIntegerArray};
+
+/// A bloom filter of `M` bits that sets `K` bits per inserted item, stored in a 1 bit [`PackedIntegerArray`].
+///
+/// The `K` bit positions of an item are derived from a single 64 bit hash by double hashing, with the hash produced by the
+/// [`BuildHasher`] `S`. By default `S` is [`RandomState`], which makes the bit positions differ between filters; use
+/// [`PackedBloom::with_hasher()`] with a deterministic hasher if filters have to be compared or sent elsewhere.
+///
+/// ```rust
+/// let mut bloom = pia::PackedBloom::<1024, 3>::new();
+///
+/// bloom.insert("stone");
+/// bloom.insert("dirt");
+///
+/// assert!(bloom.contains("stone"));
+/// assert!(!bloom.contains("bedrock"));
+/// ```
+pub struct PackedBloom<const M: usize, const K: usize, S = RandomState>
+where [u8; get_array_length(1, M)]: Sized {
+    bits: PackedIntegerArray<1, M>,
+    hasher: S
+}
+
+impl <const M: usize, const K: usize> PackedBloom<M, K, RandomState>
+where [u8; get_array_length(1, M)]: Sized {
+    /// Constructs a new empty bloom filter that hashes items with [`RandomState`].
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl <const M: usize, const K: usize, S: BuildHasher> PackedBloom<M, K, S>
+where [u8; get_array_length(1, M)]: Sized {
+    const VALID_SHAPE: () = if M == 0 || K == 0 {
+        panic!("a bloom filter needs at least one bit and at least one bit per item");
+    };
+
+    /// Constructs a new empty bloom filter that hashes items with `hasher`.
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasherDefault;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let mut bloom = pia::PackedBloom::<256, 2, _>::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    /// bloom.insert(&42);
+    /// assert!(bloom.contains(&42));
+    /// ```
+    ///
+    /// Note: a filter without any bits or that sets no bits per item can not hold anything, so an `M` or `K` of 0 is rejected at
+    /// compile time.
+    /// ```compile_fail
+    /// let bloom = pia::PackedBloom::<0, 3>::new();
+    /// ```
+    /// ```compile_fail
+    /// let bloom = pia::PackedBloom::<1024, 0>::new();
+    /// ```
+    pub fn with_hasher(hasher: S) -> Self {
+        // Evaluating the constant is what rejects empty shapes at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SHAPE;
+
+        Self {
+            bits: PackedIntegerArray::new(),
+            hasher
+        }
+    }
+
+    /// Adds `item` to the filter.
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        let (h1, h2) = self.hash_pair(item);
+        for i in 0..K {
+            self.bits.set(Self::bit_index(h1, h2, i), 1);
+        }
+    }
+
+    /// Returns `true` if `item` may have been added to the filter, or `false` if it definitely was not.
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        let (h1, h2) = self.hash_pair(item);
+        (0..K).all(|i| self.bits.get(Self::bit_index(h1, h2, i)) == 1)
+    }
+
+    /// Removes every item from the filter.
+    ///
+    /// ```rust
+    /// let mut bloom = pia::PackedBloom::<1024, 3>::new();
+    /// bloom.insert("stone");
+    /// bloom.clear();
+    /// assert!(!bloom.contains("stone"));
+    /// ```
+    pub fn clear(&mut self) {
+        self.bits = PackedIntegerArray::new();
+    }
+
+    /// Returns the underlying 1 bit packed array.
+    pub fn bits(&self) -> &PackedIntegerArray<1, M> {
+        &self.bits
+    }
+
+    fn hash_pair<T: Hash + ?Sized>(&self, item: &T) -> (u64, u64) {
+        hash_pair(&self.hasher, item)
+    }
+
+    fn bit_index(h1: u64, h2: u64, i: usize) -> usize {
+        probe_index(h1, h2, i, M)
+    }
+}
+
+/// Splits the hash of `item` into the two hashes used for double hashing.
+pub(crate) fn hash_pair<S: BuildHasher, T: Hash + ?Sized>(build_hasher: &S, item: &T) -> (u64, u64) {
+    let mut hasher = build_hasher.build_hasher();
+    item.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    // The second hash is forced odd so that it never degenerates into probing the same index over and over
+    (hash & 0xffff_ffff, (hash >> 32) | 1)
+}
+
+/// Returns the `i`th probed index out of `len` for the hashes returned by [`hash_pair()`].
+pub(crate) fn probe_index(h1: u64, h2: u64, i: usize, len: usize) -> usize {
+    (h1.wrapping_add(h2.wrapping_mul(i as u64)) % (len as u64)) as usize
+}
+
+impl <const M: usize, const K: usize> Default for PackedBloom<M, K, RandomState>
+where [u8; get_array_length(1, M)]: Sized {
+    fn default() -> Self {
+        Self::new()
+    }
+}
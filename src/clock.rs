@@ -0,0 +1,80 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Finds the first item equal to `value`, sets it to 0, and returns its index, or returns `None` if no item is equal to `value`.
+    ///
+    /// ```rust
+    /// let mut referenced = pia::PackedIntegerArray::<1, 8>::new();
+    /// referenced.set(2, 1);
+    /// referenced.set(5, 1);
+    ///
+    /// assert_eq!(referenced.find_and_clear_first(1), Some(2));
+    /// assert_eq!(referenced.find_and_clear_first(1), Some(5));
+    /// assert_eq!(referenced.find_and_clear_first(1), None);
+    /// ```
+    pub fn find_and_clear_first(&mut self, value: u8) -> Option<usize> {
+        let index = (0..LEN).find(|&i| self.get(i) == value)?;
+        self.clear(index);
+        Some(index)
+    }
+}
+
+/// The hand of a clock (second chance) eviction policy sweeping circularly over a [`PackedIntegerArray`] of small reference
+/// counters.
+///
+/// Each item is the reference counter of a slot, typically 1 or 2 bits wide. When a victim is needed the hand sweeps forward,
+/// decrementing every counter it passes over, and stops at the first counter that is already 0.
+///
+/// ```rust
+/// let mut referenced = pia::PackedIntegerArray::<1, 4>::new();
+/// let mut clock = pia::ClockCursor::new();
+///
+/// // Slots 0 and 1 were referenced recently, so they get a second chance
+/// referenced.set(0, 1);
+/// referenced.set(1, 1);
+///
+/// assert_eq!(clock.next_victim(&mut referenced), 2);
+/// assert_eq!(clock.next_victim(&mut referenced), 3);
+/// // Slot 0 lost its reference bit on the first sweep
+/// assert_eq!(clock.next_victim(&mut referenced), 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockCursor {
+    hand: usize
+}
+
+impl ClockCursor {
+    /// Constructs a new clock hand pointing at the first item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index the hand is currently pointing at.
+    pub fn hand(&self) -> usize {
+        self.hand
+    }
+
+    /// Sweeps the hand over `counters` until it finds a counter that is 0, decrementing every counter it passes over, and returns
+    /// the index of that counter. The hand is left pointing at the item after the victim.
+    ///
+    /// Note: if `LEN` is 0 the program will panic.
+    #[track_caller]
+    pub fn next_victim<const BITS: u8, const LEN: usize>(&mut self, counters: &mut PackedIntegerArray<BITS, LEN>) -> usize
+    where [u8; get_array_length(BITS, LEN)]: Sized {
+        if LEN == 0 {
+            panic!("can not choose a victim from an empty packed array");
+        }
+
+        loop {
+            let index = self.hand % LEN;
+            self.hand = (index + 1) % LEN;
+
+            let count = counters.get(index);
+            if count == 0 {
+                return index;
+            }
+            counters.set(index, count - 1);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{bit_offset_of, get_array_length, warn_if_truncated, PackedIntegerArray};
+
+/// A cursor that walks a [`PackedIntegerArray`] one item at a time, keeping track of the byte and bit offset of the current item
+/// incrementally instead of recomputing it on every access.
+///
+/// Use [`PackedIntegerArray::cursor()`] to construct a new instance.
+///
+/// ```rust
+/// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+/// let mut cursor = packed_array.cursor();
+///
+/// cursor.write(5);
+/// cursor.write(2);
+/// cursor.seek(0);
+///
+/// assert_eq!(cursor.read(), Some(5));
+/// assert_eq!(cursor.peek(), Some(2));
+/// ```
+pub struct Cursor<'a, const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    array: &'a mut PackedIntegerArray<BITS, LEN>,
+    index: usize,
+    start_byte: usize, // The index of the byte that contains the start of the current item
+    start_bit: usize // The first bit on that byte containing the start of the current item
+}
+
+impl <'a, const BITS: u8, const LEN: usize> Cursor<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new cursor positioned at the first item of `array`.
+    pub fn new(array: &'a mut PackedIntegerArray<BITS, LEN>) -> Self {
+        Self {
+            array,
+            index: 0,
+            start_byte: 0,
+            start_bit: 0
+        }
+    }
+
+    /// Returns the index of the item the cursor is currently positioned at.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// let mut cursor = packed_array.cursor();
+    ///
+    /// cursor.skip(4);
+    /// assert_eq!(cursor.position(), 4);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.index
+    }
+
+    /// Moves the cursor to the item at `index`.
+    ///
+    /// Seeking past the end of the array is allowed, after which [`Cursor::read()`] and [`Cursor::peek()`] return `None`.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(6, 3);
+    ///
+    /// let mut cursor = packed_array.cursor();
+    /// cursor.seek(6);
+    /// assert_eq!(cursor.read(), Some(3));
+    ///
+    /// cursor.seek(usize::MAX);
+    /// assert_eq!(cursor.read(), None);
+    /// ```
+    pub fn seek(&mut self, index: usize) {
+        self.index = index;
+
+        // Items past the end are never accessed, so their offset is clamped to keep it from overflowing
+        let bit_offset = bit_offset_of(BITS, usize::min(index, LEN));
+        self.start_byte = bit_offset / (u8::BITS as usize);
+        self.start_bit = bit_offset % (u8::BITS as usize);
+    }
+
+    /// Advances the cursor by `n` items without reading them.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(2, 6);
+    ///
+    /// let mut cursor = packed_array.cursor();
+    /// cursor.skip(2);
+    /// assert_eq!(cursor.read(), Some(6));
+    ///
+    /// cursor.skip(usize::MAX);
+    /// assert_eq!(cursor.position(), usize::MAX);
+    /// assert_eq!(cursor.read(), None);
+    /// ```
+    pub fn skip(&mut self, n: usize) {
+        self.seek(self.index.saturating_add(n));
+    }
+
+    /// Returns the item the cursor is positioned at without advancing, or `None` if the cursor is past the end of the array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(0, 4);
+    ///
+    /// let mut cursor = packed_array.cursor();
+    /// assert_eq!(cursor.peek(), Some(4));
+    /// assert_eq!(cursor.peek(), Some(4));
+    /// ```
+    pub fn peek(&self) -> Option<u8> {
+        if self.index < LEN {
+            Some(self.array.get_at(self.start_byte, self.start_bit))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item the cursor is positioned at and advances to the next one, or `None` if the cursor is past the end of the array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 2>::new();
+    /// packed_array.set(1, 7);
+    ///
+    /// let mut cursor = packed_array.cursor();
+    /// assert_eq!(cursor.read(), Some(0));
+    /// assert_eq!(cursor.read(), Some(7));
+    /// assert_eq!(cursor.read(), None);
+    /// ```
+    pub fn read(&mut self) -> Option<u8> {
+        let result = self.peek();
+        if result.is_some() {
+            self.advance();
+        }
+        result
+    }
+
+    /// Sets the item the cursor is positioned at to `value` and advances to the next one.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// let mut cursor = packed_array.cursor();
+    ///
+    /// cursor.skip(3);
+    /// cursor.write(7);
+    /// assert_eq!(packed_array.get(3), 7);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], if the cursor is past the end of the array the program will panic, and
+    /// overflowing bits of `value` are truncated.
+    #[track_caller]
+    pub fn write(&mut self, value: u8) {
+        if self.index >= LEN {
+            // Defer to set for the out of bounds panic
+            self.array.set(self.index, value);
+        }
+
+        warn_if_truncated(value, BITS);
+        self.array.set_at(self.start_byte, self.start_bit, value);
+        self.advance();
+    }
+
+    fn advance(&mut self) {
+        self.index += 1;
+        self.start_bit += BITS as usize;
+        self.start_byte += self.start_bit / (u8::BITS as usize);
+        self.start_bit %= (u8::BITS as usize);
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns a [`Cursor`] positioned at the first item of the array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(1, 2);
+    ///
+    /// let mut cursor = packed_array.cursor();
+    /// cursor.skip(1);
+    /// assert_eq!(cursor.read(), Some(2));
+    /// ```
+    pub fn cursor(&mut self) -> Cursor<'_, BITS, LEN> {
+        Cursor::new(self)
+    }
+}
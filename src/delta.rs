@@ -0,0 +1,116 @@
+use crate::{get_array_length, Error, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Serializes only the items that differ from `baseline` into a compact list of bytes.
+    ///
+    /// Every changed item is encoded as the distance from the previous changed item as a LEB128 variable length integer followed by
+    /// the new value as a single byte, so arrays that differ in a handful of items serialize to a handful of bytes.
+    ///
+    /// ```rust
+    /// let baseline = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(2, 5);
+    /// packed_array.set(7, 1);
+    ///
+    /// let delta = packed_array.serialize_delta(&baseline);
+    /// assert_eq!(delta, [2, 5, 4, 1]);
+    /// ```
+    pub fn serialize_delta(&self, baseline: &Self) -> Vec<u8> {
+        let mut delta = Vec::new();
+        let mut next = 0;
+        for i in 0..LEN {
+            let value = self.get(i);
+            if value != baseline.get(i) {
+                let mut gap = i - next;
+                loop {
+                    if gap < 0x80 {
+                        delta.push(gap as u8);
+                        break;
+                    }
+                    delta.push((gap as u8 & 0x7f) | 0x80);
+                    gap >>= 7;
+                }
+                delta.push(value);
+                next = i + 1;
+            }
+        }
+        delta
+    }
+
+    /// Applies a delta produced by [`PackedIntegerArray::serialize_delta()`] on top of this array.
+    ///
+    /// The whole delta is validated before anything is written, so on error the array is left untouched.
+    ///
+    /// ```rust
+    /// let baseline = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(2, 5);
+    /// let delta = packed_array.serialize_delta(&baseline);
+    ///
+    /// let mut replica = pia::PackedIntegerArray::<3, 9>::new();
+    /// replica.apply_serialized_delta(&delta).unwrap();
+    /// assert_eq!(replica, packed_array);
+    ///
+    /// assert_eq!(replica.apply_serialized_delta(&[9, 1]), Err(pia::Error::IndexOutOfBounds { index: 9, len: 9 }));
+    /// assert_eq!(replica.apply_serialized_delta(&[0]), Err(pia::Error::Truncated { offset: 1 }));
+    /// assert_eq!(replica.apply_serialized_delta(&[0, 8]), Err(pia::Error::ValueOverflow { value: 8, bits: 3 }));
+    ///
+    /// // A gap of 2^64 + 1 does not fit in a `usize`, so it is out of bounds instead of wrapping around to index 1
+    /// let huge_gap = [0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02, 1];
+    /// assert!(matches!(replica.apply_serialized_delta(&huge_gap), Err(pia::Error::IndexOutOfBounds { .. })));
+    ///
+    /// assert_eq!(replica, packed_array);
+    /// ```
+    pub fn apply_serialized_delta(&mut self, delta: &[u8]) -> Result<(), Error> {
+        Self::decode_delta(delta, |_, _| {})?;
+        Self::decode_delta(delta, |index, value| self.set(index, value))
+    }
+
+    fn decode_delta<F: FnMut(usize, u8)>(delta: &[u8], mut apply: F) -> Result<(), Error> {
+        let byte_at = |offset: usize| delta.get(offset).copied().ok_or(Error::Truncated { offset });
+
+        let mut offset = 0;
+        let mut next = 0usize;
+        while offset < delta.len() {
+            let mut gap = 0usize;
+            let mut shift = 0u32;
+            loop {
+                let byte = byte_at(offset)?;
+                offset += 1;
+                // A gap too large for a `usize` can only point past the end of the array, so any bits shifted out of it saturate it
+                let bits = (byte & 0x7f) as usize;
+                gap = match bits.checked_shl(shift) {
+                    Some(shifted) if shifted >> shift == bits => gap | shifted,
+                    _ if bits == 0 => gap,
+                    _ => usize::MAX
+                };
+                shift = shift.saturating_add(7);
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+
+            let value = byte_at(offset)?;
+            offset += 1;
+            let index = next.saturating_add(gap);
+            if index >= LEN {
+                return Err(Error::IndexOutOfBounds {
+                    index,
+                    len: LEN
+                });
+            }
+            if value > Self::MAX_VALUE {
+                return Err(Error::ValueOverflow {
+                    value: value as u64,
+                    bits: BITS
+                });
+            }
+            apply(index, value);
+            next = index + 1;
+        }
+        Ok(())
+    }
+}
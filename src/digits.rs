@@ -0,0 +1,74 @@
+use crate::{get_array_length, Error, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new packed integer array holding one digit of `digits` per item, leaving any items past the last digit at 0.
+    ///
+    /// Digits are parsed with [`char::to_digit()`], so both upper and lower case letters are accepted for radices above 10.
+    ///
+    /// ```rust
+    /// let scores = pia::PackedIntegerArray::<4, 6>::from_digits_str("31025", 10).unwrap();
+    /// assert_eq!(scores, [3, 1, 0, 2, 5, 0]);
+    ///
+    /// let invalid = pia::PackedIntegerArray::<4, 6>::from_digits_str("3A", 10);
+    /// assert_eq!(invalid.unwrap_err(), pia::Error::InvalidDigit { offset: 1, character: 'A' });
+    ///
+    /// let too_long = pia::PackedIntegerArray::<4, 6>::from_digits_str("3102500", 10);
+    /// assert_eq!(too_long.unwrap_err(), pia::Error::LengthMismatch { expected: 6, found: 7 });
+    /// ```
+    ///
+    /// Note: if `radix` is less than 2, greater than 36, or has digits that do not fit in `BITS` bits the program will panic.
+    pub fn from_digits_str(digits: &str, radix: u32) -> Result<Self, Error> {
+        Self::check_radix(radix);
+
+        let found = digits.chars().count();
+        if found > LEN {
+            return Err(Error::LengthMismatch {
+                expected: LEN,
+                found
+            });
+        }
+
+        let mut array = Self::new();
+        for (i, (offset, character)) in digits.char_indices().enumerate() {
+            match character.to_digit(radix) {
+                Some(digit) => array.set(i, digit as u8),
+                None => return Err(Error::InvalidDigit {
+                    offset,
+                    character
+                })
+            }
+        }
+        Ok(array)
+    }
+
+    /// Returns a string of one digit per item, or `None` if any item is not a digit of `radix`.
+    ///
+    /// Digits above 9 are formatted as lower case letters, just like [`char::from_digit()`].
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<4, 4>::new();
+    /// packed_array.set(0, 3);
+    /// packed_array.set(2, 11);
+    ///
+    /// assert_eq!(packed_array.to_digits_string(16), Some(String::from("30b0")));
+    /// assert_eq!(packed_array.to_digits_string(10), None);
+    /// ```
+    ///
+    /// Note: if `radix` is less than 2, greater than 36, or has digits that do not fit in `BITS` bits the program will panic.
+    pub fn to_digits_string(&self, radix: u32) -> Option<String> {
+        Self::check_radix(radix);
+
+        (0..LEN).map(|i| std::char::from_digit(self.get(i) as u32, radix)).collect()
+    }
+
+    #[track_caller]
+    fn check_radix(radix: u32) {
+        if !(2..=36).contains(&radix) {
+            panic!("radix must be between 2 and 36 but the radix is {}", radix);
+        }
+        if radix - 1 > Self::MAX_VALUE as u32 {
+            panic!("digits of radix {} do not fit in {} bits", radix, BITS);
+        }
+    }
+}
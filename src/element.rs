@@ -0,0 +1,255 @@
+use std::marker::PhantomData;
+
+use crate::get_array_length;
+
+/// A type that can be stored in a [`TypedPackedArray`] by converting it to and from a fixed amount of bits.
+///
+/// ```rust
+/// use pia::PackedElement;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Facing { North, East, South, West }
+///
+/// impl PackedElement for Facing {
+///     const WIDTH: u8 = 2;
+///
+///     fn to_bits(&self) -> u64 {
+///         *self as u64
+///     }
+///
+///     fn from_bits(bits: u64) -> Self {
+///         match bits {
+///             0 => Facing::North,
+///             1 => Facing::East,
+///             2 => Facing::South,
+///             _ => Facing::West
+///         }
+///     }
+/// }
+///
+/// let mut facings = pia::TypedPackedArray::<Facing, 16>::new();
+/// facings.set(3, Facing::South);
+/// assert_eq!(facings.get(3), Facing::South);
+/// ```
+pub trait PackedElement {
+    /// The amount of bits every value takes up, at most 64.
+    const WIDTH: u8;
+
+    /// Converts the value to bits. Only the lowest [`PackedElement::WIDTH`] bits are stored, any bits above are truncated.
+    fn to_bits(&self) -> u64;
+
+    /// Converts bits returned by [`PackedElement::to_bits()`] back to a value. The bits above [`PackedElement::WIDTH`] are always 0.
+    fn from_bits(bits: u64) -> Self;
+}
+
+impl PackedElement for bool {
+    const WIDTH: u8 = 1;
+
+    fn to_bits(&self) -> u64 {
+        *self as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+
+macro_rules! impl_packed_element {
+    ($($int:ty),*) => {
+        $(
+            impl PackedElement for $int {
+                const WIDTH: u8 = <$int>::BITS as u8;
+
+                fn to_bits(&self) -> u64 {
+                    *self as u64
+                }
+
+                fn from_bits(bits: u64) -> Self {
+                    bits as $int
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_element!(u8, u16, u32, u64);
+
+/// Optional values take up one extra bit above the bits of the value, which is set for `Some` and clear for `None`.
+///
+/// Since a new [`TypedPackedArray`] stores every value as all zero bits, an array of optional values starts out full of `None`.
+///
+/// Note: since elements are at most 64 bits wide, `T::WIDTH` can be at most 63, so for example `Option<u64>` can't be stored in a
+/// [`TypedPackedArray`].
+///
+/// ```rust
+/// let mut slots = pia::TypedPackedArray::<Option<u8>, 8>::new();
+/// assert_eq!(slots.get(2), None);
+///
+/// slots.set(2, Some(0));
+/// assert_eq!(slots.get(2), Some(0));
+///
+/// // 8 bits for the value plus 1 for whether it is there
+/// assert_eq!(<Option<u8> as pia::PackedElement>::WIDTH, 9);
+/// ```
+impl <T: PackedElement> PackedElement for Option<T> {
+    const WIDTH: u8 = T::WIDTH + 1;
+
+    fn to_bits(&self) -> u64 {
+        match self {
+            Some(value) => (1 << T::WIDTH) | (value.to_bits() & (u64::MAX >> ((u64::BITS as u8) - T::WIDTH))),
+            None => 0
+        }
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        if (bits >> T::WIDTH) & 1 == 1 {
+            Some(T::from_bits(bits & !(1 << T::WIDTH)))
+        } else {
+            None
+        }
+    }
+}
+
+/// A wrapped array that bit packs `LEN` amount of values of any [`PackedElement`] type into an array of `u8`s.
+///
+/// Values are packed the same way as in a [`PackedIntegerArray`](crate::PackedIntegerArray), so a `TypedPackedArray` of an element
+/// `WIDTH` bits wide has the same layout as a `PackedIntegerArray` of `WIDTH` bits per item.
+///
+/// ```rust
+/// // 12 bit block ids
+/// struct BlockId(u16);
+///
+/// impl pia::PackedElement for BlockId {
+///     const WIDTH: u8 = 12;
+///
+///     fn to_bits(&self) -> u64 {
+///         self.0 as u64
+///     }
+///
+///     fn from_bits(bits: u64) -> Self {
+///         BlockId(bits as u16)
+///     }
+/// }
+///
+/// let mut blocks = pia::TypedPackedArray::<BlockId, 4096>::new();
+/// blocks.set(100, BlockId(4000));
+///
+/// assert_eq!(blocks.get(100).0, 4000);
+/// assert_eq!(blocks.as_ref().len(), 6144);
+/// ```
+pub struct TypedPackedArray<T: PackedElement, const LEN: usize>
+where [u8; get_array_length(T::WIDTH, LEN)]: Sized {
+    content: [u8; get_array_length(T::WIDTH, LEN)],
+    element: PhantomData<T>
+}
+
+impl <T: PackedElement, const LEN: usize> TypedPackedArray<T, LEN>
+where [u8; get_array_length(T::WIDTH, LEN)]: Sized {
+    const VALID_WIDTH: () = if T::WIDTH > (u64::BITS as u8) {
+        panic!("packed elements can be at most 64 bits wide");
+    };
+
+    /// Constructs a new array with every value stored as all zero bits.
+    ///
+    /// Elements wider than 64 bits are rejected at compile time:
+    ///
+    /// ```rust,compile_fail
+    /// let slots = pia::TypedPackedArray::<Option<u64>, 8>::new();
+    /// ```
+    pub fn new() -> Self {
+        // Evaluating the constant is what rejects elements that are too wide at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_WIDTH;
+
+        Self {
+            content: [0; get_array_length(T::WIDTH, LEN)],
+            element: PhantomData
+        }
+    }
+
+    /// Returns the value at `index` in the array.
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn get(&self, index: usize) -> T {
+        if index >= LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        T::from_bits(read_bits(&self.content, index * (T::WIDTH as usize), T::WIDTH as usize))
+    }
+
+    /// Sets the value at `index` in the array to `value`.
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is set the program will panic.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, value: T) {
+        if index >= LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        write_bits(&mut self.content, index * (T::WIDTH as usize), T::WIDTH as usize, value.to_bits());
+    }
+
+    /// Returns the amount of values in the array.
+    pub const fn len(&self) -> usize {
+        LEN
+    }
+
+    /// Returns `true` if the array holds no values.
+    pub const fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+}
+
+impl <T: PackedElement, const LEN: usize> Default for TypedPackedArray<T, LEN>
+where [u8; get_array_length(T::WIDTH, LEN)]: Sized {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <T: PackedElement, const LEN: usize> AsRef<[u8]> for TypedPackedArray<T, LEN>
+where [u8; get_array_length(T::WIDTH, LEN)]: Sized {
+    fn as_ref(&self) -> &[u8] {
+        &self.content[..]
+    }
+}
+
+impl <T: PackedElement, const LEN: usize> AsMut<[u8]> for TypedPackedArray<T, LEN>
+where [u8; get_array_length(T::WIDTH, LEN)]: Sized {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.content[..]
+    }
+}
+
+/// Reads `width` bits starting `bit` bits into `content`, most significant bit first.
+fn read_bits(content: &[u8], mut bit: usize, mut width: usize) -> u64 {
+    let mut value = 0;
+    while width > 0 {
+        let start_bit = bit % (u8::BITS as usize);
+        let taken = usize::min((u8::BITS as usize) - start_bit, width);
+        let bits = (content[bit / (u8::BITS as usize)] >> ((u8::BITS as usize) - start_bit - taken)) & (u8::MAX >> ((u8::BITS as usize) - taken));
+
+        value = (value << taken) | (bits as u64);
+        bit += taken;
+        width -= taken;
+    }
+    value
+}
+
+/// Writes the lowest `width` bits of `value` starting `bit` bits into `content`, most significant bit first.
+fn write_bits(content: &mut [u8], mut bit: usize, mut width: usize, value: u64) {
+    while width > 0 {
+        let start_bit = bit % (u8::BITS as usize);
+        let taken = usize::min((u8::BITS as usize) - start_bit, width);
+        let shift = (u8::BITS as usize) - start_bit - taken;
+        let mask = (u8::MAX >> ((u8::BITS as usize) - taken)) << shift;
+        let bits = (((value >> (width - taken)) as u8) << shift) & mask;
+
+        let byte = &mut content[bit / (u8::BITS as usize)];
+        *byte = (*byte & !mask) | bits;
+        bit += taken;
+        width -= taken;
+    }
+}
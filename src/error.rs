@@ -0,0 +1,76 @@
+use std::fmt;
+
+/// The error returned by every fallible operation of the crate.
+///
+/// Every variant carries the context needed to report or recover from the failure, so the error can be passed along with `?`.
+///
+/// ```rust
+/// fn load(bytes: &[u8]) -> Result<u8, pia::Error> {
+///     let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+///     packed_array.apply_serialized_delta(bytes)?;
+///     packed_array.try_set(0, 7)?;
+///     Ok(packed_array.get(2))
+/// }
+///
+/// assert_eq!(load(&[2, 5]), Ok(5));
+/// assert_eq!(load(&[2]), Err(pia::Error::Truncated { offset: 1 }));
+/// assert_eq!(load(&[2]).unwrap_err().to_string(), "input ends in the middle of an entry at byte 1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The index of the item is outside of the array bounds.
+    IndexOutOfBounds {
+        /// The index of the item.
+        index: usize,
+        /// The amount of items in the array.
+        len: usize
+    },
+    /// The value is greater than the maximum value representable with the amount of bits per item.
+    ValueOverflow {
+        /// The value, widened so that values of any unsigned integer type can be reported.
+        value: u64,
+        /// The amount of bits per item.
+        bits: u8
+    },
+    /// The amount of values does not match the amount of items in the array.
+    LengthMismatch {
+        /// The amount of items in the array.
+        expected: usize,
+        /// The amount of values.
+        found: usize
+    },
+    /// The value was rejected by the validator of a [`ValidatedPackedArray`](crate::ValidatedPackedArray).
+    Rejected {
+        /// The index of the item.
+        index: usize,
+        /// The value.
+        value: u8
+    },
+    /// The parsed input ends in the middle of an entry.
+    Truncated {
+        /// The byte offset into the input where another byte was expected.
+        offset: usize
+    },
+    /// The parsed input holds a character that is not a digit of the radix.
+    InvalidDigit {
+        /// The byte offset of the character into the input.
+        offset: usize,
+        /// The character.
+        character: char
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IndexOutOfBounds { index, len } => write!(f, "index out of bounds: the len is {} but the index is {}", len, index),
+            Error::ValueOverflow { value, bits } => write!(f, "value {} does not fit in {} bits", value, bits),
+            Error::LengthMismatch { expected, found } => write!(f, "expected {} values but found {}", expected, found),
+            Error::Rejected { index, value } => write!(f, "value {} was rejected by the validator at index {}", value, index),
+            Error::Truncated { offset } => write!(f, "input ends in the middle of an entry at byte {}", offset),
+            Error::InvalidDigit { offset, character } => write!(f, "invalid digit {:?} at byte {}", character, offset)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,84 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{get_array_length, PackedIntegerArray};
+
+/// An immutable [`PackedIntegerArray`] meant to be shared behind an [`Arc`] between readers.
+///
+/// Use [`PackedIntegerArray::freeze()`] to construct a new instance and [`FrozenPackedArray::thaw()`] to get a mutable array back.
+/// All of the read only methods of [`PackedIntegerArray`] are available through [`Deref`].
+///
+/// ```rust
+/// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+/// packed_array.set(3, 7);
+///
+/// let frozen = packed_array.freeze();
+/// let shared = frozen.clone();
+///
+/// assert_eq!(shared.get(3), 7);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct FrozenPackedArray<const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    array: PackedIntegerArray<BITS, LEN>
+}
+
+impl <const BITS: u8, const LEN: usize> FrozenPackedArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Turns the frozen array back into a mutable [`PackedIntegerArray`].
+    ///
+    /// If this is the last reference to the frozen array the content is moved out, otherwise it is copied.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(3, 7);
+    ///
+    /// let frozen = packed_array.freeze();
+    /// let mut thawed = frozen.clone().thaw();
+    /// thawed.set(3, 1);
+    ///
+    /// assert_eq!(thawed.get(3), 1);
+    /// assert_eq!(frozen.get(3), 7);
+    /// ```
+    pub fn thaw(self: Arc<Self>) -> PackedIntegerArray<BITS, LEN> {
+        match Arc::try_unwrap(self) {
+            Ok(frozen) => frozen.array,
+            Err(shared) => PackedIntegerArray {
+                content: shared.array.content
+            }
+        }
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> Deref for FrozenPackedArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Target = PackedIntegerArray<BITS, LEN>;
+
+    fn deref(&self) -> &<Self as Deref>::Target {
+        &self.array
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Consumes the array and returns an immutable, cheaply clonable [`FrozenPackedArray`] that can be shared between threads.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(3, 7);
+    ///
+    /// let frozen = packed_array.freeze();
+    /// let reader = {
+    ///     let frozen = frozen.clone();
+    ///     std::thread::spawn(move || frozen.get(3))
+    /// };
+    ///
+    /// assert_eq!(reader.join().unwrap(), 7);
+    /// ```
+    pub fn freeze(self) -> Arc<FrozenPackedArray<BITS, LEN>> {
+        Arc::new(FrozenPackedArray {
+            array: self
+        })
+    }
+}
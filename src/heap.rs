@@ -0,0 +1,137 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+/// A fixed capacity max-heap of up to `LEN` values of `BITS` bits each, kept in heap order inside a [`PackedIntegerArray`].
+///
+/// ```rust
+/// let mut queue = pia::PackedBinaryHeap::<3, 8>::new();
+///
+/// queue.push(2).unwrap();
+/// queue.push(7).unwrap();
+/// queue.push(5).unwrap();
+///
+/// assert_eq!(queue.peek(), Some(7));
+/// assert_eq!(queue.pop_max(), Some(7));
+/// assert_eq!(queue.pop_max(), Some(5));
+/// assert_eq!(queue.pop_max(), Some(2));
+/// assert_eq!(queue.pop_max(), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PackedBinaryHeap<const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    items: PackedIntegerArray<BITS, LEN>,
+    len: usize
+}
+
+impl <const BITS: u8, const LEN: usize> PackedBinaryHeap<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new empty heap.
+    pub fn new() -> Self {
+        Self {
+            items: PackedIntegerArray::new(),
+            len: 0
+        }
+    }
+
+    /// Returns the amount of values in the heap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the heap holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the heap holds `LEN` values and can not take any more.
+    pub fn is_full(&self) -> bool {
+        self.len == LEN
+    }
+
+    /// Returns the greatest value in the heap without removing it, or `None` if the heap is empty.
+    pub fn peek(&self) -> Option<u8> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.items.get(0))
+        }
+    }
+
+    /// Adds `value` to the heap, or gives it back as an error if the heap is full.
+    ///
+    /// ```rust
+    /// let mut queue = pia::PackedBinaryHeap::<3, 1>::new();
+    /// assert_eq!(queue.push(4), Ok(()));
+    /// assert_eq!(queue.push(6), Err(6));
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of `value` are truncated.
+    pub fn push(&mut self, value: u8) -> Result<(), u8> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        let mut index = self.len;
+        self.items.set(index, value);
+        self.len += 1;
+
+        // Sift the new value up until its parent is at least as great
+        let value = self.items.get(index);
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            let parent_value = self.items.get(parent);
+            if parent_value >= value {
+                break;
+            }
+            self.items.set(index, parent_value);
+            index = parent;
+        }
+        self.items.set(index, value);
+
+        Ok(())
+    }
+
+    /// Removes and returns the greatest value in the heap, or `None` if the heap is empty.
+    pub fn pop_max(&mut self) -> Option<u8> {
+        let max = self.peek()?;
+
+        self.len -= 1;
+        let value = self.items.get(self.len);
+        self.items.clear(self.len);
+
+        // Sift the last value down from the root until both of its children are at most as great
+        let mut index = 0;
+        loop {
+            let left = index * 2 + 1;
+            if left >= self.len {
+                break;
+            }
+
+            let right = left + 1;
+            let child = if right < self.len && self.items.get(right) > self.items.get(left) { right } else { left };
+            let child_value = self.items.get(child);
+            if child_value <= value {
+                break;
+            }
+            self.items.set(index, child_value);
+            index = child;
+        }
+        if self.len > 0 {
+            self.items.set(index, value);
+        }
+
+        Some(max)
+    }
+
+    /// Removes every value from the heap.
+    pub fn clear(&mut self) {
+        self.items = PackedIntegerArray::new();
+        self.len = 0;
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> Default for PackedBinaryHeap<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Combines two arrays into one twice as long, alternating between the items of `a` and `b`.
+    ///
+    /// ```rust
+    /// let mut left = pia::PackedIntegerArray::<3, 3>::new();
+    /// let mut right = pia::PackedIntegerArray::<3, 3>::new();
+    /// left.set(0, 1);
+    /// right.set(0, 2);
+    /// left.set(2, 5);
+    ///
+    /// let stereo = pia::PackedIntegerArray::interleave(&left, &right);
+    /// assert_eq!(stereo, [1, 2, 0, 0, 5, 0]);
+    /// ```
+    pub fn interleave(a: &Self, b: &Self) -> PackedIntegerArray<BITS, { 2 * LEN }>
+    where [u8; get_array_length(BITS, 2 * LEN)]: Sized {
+        let mut combined = PackedIntegerArray::<BITS, { 2 * LEN }>::new();
+        for i in 0..LEN {
+            combined.set(i * 2, a.get(i));
+            combined.set(i * 2 + 1, b.get(i));
+        }
+        combined
+    }
+
+    /// Splits an array into two half as long, the first holding the items at even indices and the second the items at odd
+    /// indices. This is the inverse of [`PackedIntegerArray::interleave()`].
+    ///
+    /// ```rust
+    /// let mut stereo = pia::PackedIntegerArray::<3, 6>::new();
+    /// for i in 0..6 {
+    ///     stereo.set(i, i as u8);
+    /// }
+    ///
+    /// let (left, right) = pia::PackedIntegerArray::<3, 3>::deinterleave(&stereo);
+    /// assert_eq!(left, [0, 2, 4]);
+    /// assert_eq!(right, [1, 3, 5]);
+    /// ```
+    pub fn deinterleave(combined: &PackedIntegerArray<BITS, { 2 * LEN }>) -> (Self, Self)
+    where [u8; get_array_length(BITS, 2 * LEN)]: Sized {
+        let mut a = Self::new();
+        let mut b = Self::new();
+        for i in 0..LEN {
+            a.set(i, combined.get(i * 2));
+            b.set(i, combined.get(i * 2 + 1));
+        }
+        (a, b)
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{bit_offset_of, get_array_length, padding_bits, PackedIntegerArray};
+
+/// The version of the packed content layout, which is bumped whenever the way items are packed into `u8`s changes.
+///
+/// A [`PackedIntegerArray`] is `#[repr(transparent)]` over its content, so as long as this version and the [`Layout`] match, the
+/// bytes of an array can be shared with another process or another build of this crate, for example through shared memory.
+///
+/// ```rust
+/// // Store the version next to the array so readers can reject content they do not understand
+/// let header = pia::LAYOUT_VERSION.to_le_bytes();
+/// assert_eq!(u32::from_le_bytes(header), pia::LAYOUT_VERSION);
+/// ```
+pub const LAYOUT_VERSION: u32 = 1;
+
+/// A description of how `len` amount of items each of `bits` amount of bits are laid out in the packed content.
+///
+/// Use [`layout()`] or [`PackedIntegerArray::layout()`] to compute the layout of an array. Since both are `const`, the layout can
+/// be checked at compile time, for example to lock in the layout a downstream crate depends on.
+///
+/// ```rust
+/// const LAYOUT: pia::Layout = pia::layout(3, 9);
+///
+/// // Fails to compile if the amount of bytes ever changes
+/// const _: [(); 4] = [(); LAYOUT.bytes];
+///
+/// assert_eq!(LAYOUT.padding_bits, 5);
+/// assert!(LAYOUT.spans_bytes);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Layout {
+    /// The amount of bits per item.
+    pub bits: u8,
+    /// The amount of items.
+    pub len: usize,
+    /// The amount of `u8`s housing the items, see [`get_array_length()`].
+    pub bytes: usize,
+    /// The amount of unused bits at the end of the last `u8`, see [`padding_bits()`].
+    pub padding_bits: usize,
+    /// Whether any item is split over two `u8`s.
+    pub spans_bytes: bool
+}
+
+/// Computes the [`Layout`] of an array of `len` amount of items each of `bits` amount of bits.
+///
+/// ```rust
+/// // 4 bit items line up with the nibbles of every byte
+/// let layout = pia::layout(4, 9);
+/// assert_eq!(layout.bytes, 5);
+/// assert_eq!(layout.padding_bits, 4);
+/// assert!(!layout.spans_bytes);
+///
+/// // The third 3 bit item takes the last 2 bits of the first byte and the first bit of the second
+/// assert!(!pia::layout(3, 2).spans_bytes);
+/// assert!(pia::layout(3, 3).spans_bytes);
+/// ```
+pub const fn layout(bits: u8, len: usize) -> Layout {
+    // The offsets of the items within their bytes repeat every 8 items, so only the first 8 need to be checked
+    let mut spans_bytes = false;
+    let mut index = 0;
+    while index < len && index < (u8::BITS as usize) {
+        if (index * (bits as usize)) % (u8::BITS as usize) + (bits as usize) > (u8::BITS as usize) {
+            spans_bytes = true;
+        }
+        index += 1;
+    }
+
+    Layout {
+        bits,
+        len,
+        bytes: get_array_length(bits, len),
+        padding_bits: padding_bits(bits, len),
+        spans_bytes
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the [`Layout`] of the array, see [`layout()`].
+    ///
+    /// ```rust
+    /// const LAYOUT: pia::Layout = pia::PackedIntegerArray::<3, 9>::layout();
+    /// assert_eq!(LAYOUT, pia::layout(3, 9));
+    /// assert_eq!(LAYOUT.bytes, std::mem::size_of::<pia::PackedIntegerArray<3, 9>>());
+    /// ```
+    pub const fn layout() -> Layout {
+        layout(BITS, LEN)
+    }
+
+    /// Returns where the item at `index` lives in the packed content, as the offset of the first byte housing the item, the offset
+    /// of the first bit of the item within that byte counted from the most significant bit, and the mask of the item's bits within
+    /// the big endian 16 bit word starting at that byte.
+    ///
+    /// This is enough to point a DMA descriptor or a peripheral configuration at a single item without going through the array.
+    ///
+    /// ```rust
+    /// // The item at index 2 takes the last 2 bits of byte 0 and the first bit of byte 1
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::element_ptr(2), (0, 6, 0b00000011_10000000));
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::element_ptr(3), (1, 1, 0b01110000_00000000));
+    /// ```
+    ///
+    /// Note: just like a normal array, if `index` is outside of the array bounds the program will panic.
+    #[track_caller]
+    pub const fn element_ptr(index: usize) -> (usize, usize, u16) {
+        if index >= LEN {
+            panic!("index out of bounds");
+        }
+
+        let bit = bit_offset_of(BITS, index);
+        let byte_offset = bit / (u8::BITS as usize);
+        let bit_offset = bit % (u8::BITS as usize);
+        let mask = ((1u16 << BITS) - 1) << ((u16::BITS as usize) - bit_offset - (BITS as usize));
+        (byte_offset, bit_offset, mask)
+    }
+
+    /// Returns `true` if the packed content of this array and an array of `OTHER_LEN` amount of items each of `OTHER_BITS` amount
+    /// of bits can be read as one another, which is only the case when both have the same amount of bits per item and items.
+    ///
+    /// ```rust
+    /// assert!(pia::PackedIntegerArray::<3, 9>::is_layout_compatible::<3, 9>());
+    ///
+    /// // Both are 4 bytes, but the items are packed differently
+    /// assert!(!pia::PackedIntegerArray::<3, 9>::is_layout_compatible::<4, 8>());
+    /// ```
+    pub const fn is_layout_compatible<const OTHER_BITS: u8, const OTHER_LEN: usize>() -> bool {
+        BITS == OTHER_BITS && LEN == OTHER_LEN
+    }
+
+    /// Panics if the array is not layout compatible with an array of `OTHER_LEN` amount of items each of `OTHER_BITS` amount of
+    /// bits, see [`PackedIntegerArray::is_layout_compatible()`].
+    ///
+    /// ```rust
+    /// pia::PackedIntegerArray::<3, 9>::assert_layout_compatible::<3, 9>();
+    /// ```
+    ///
+    /// ```should_panic
+    /// pia::PackedIntegerArray::<3, 9>::assert_layout_compatible::<4, 8>();
+    /// ```
+    pub const fn assert_layout_compatible<const OTHER_BITS: u8, const OTHER_LEN: usize>() {
+        if !Self::is_layout_compatible::<OTHER_BITS, OTHER_LEN>() {
+            panic!("the packed array layouts are not compatible");
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::iter::FusedIterator;
+
+use crate::{get_array_length, PackedIntegerArray};
+
+/// The most amount of bits in total an array can have for [`PackedIntegerArray::enumerate_all()`], which is 16777216 arrays.
+const MAX_ENUMERATED_BITS: usize = 24;
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Advances the array to the next array in lexicographic order, treating it as a counter of `LEN` digits in base `2^BITS`
+    /// with the item at index 0 as the most significant digit.
+    ///
+    /// Returns `false` if the array was already the last one, in which case it wraps around to all zeroes.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<1, 2>::new();
+    ///
+    /// assert!(packed_array.increment_lex());
+    /// assert_eq!(packed_array, [0, 1]);
+    /// assert!(packed_array.increment_lex());
+    /// assert_eq!(packed_array, [1, 0]);
+    /// assert!(packed_array.increment_lex());
+    /// assert_eq!(packed_array, [1, 1]);
+    ///
+    /// assert!(!packed_array.increment_lex());
+    /// assert_eq!(packed_array, [0, 0]);
+    /// ```
+    pub fn increment_lex(&mut self) -> bool {
+        for i in (0..LEN).rev() {
+            let digit = self.get(i);
+            if digit < Self::MAX_VALUE {
+                self.set(i, digit + 1);
+                return true;
+            }
+            self.clear(i);
+        }
+        false
+    }
+
+    /// Moves the array back to the previous array in lexicographic order, see [`PackedIntegerArray::increment_lex()`].
+    ///
+    /// Returns `false` if the array was already all zeroes, in which case it wraps around to the last array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<2, 2>::new();
+    ///
+    /// assert!(!packed_array.decrement_lex());
+    /// assert_eq!(packed_array, [3, 3]);
+    /// assert!(packed_array.decrement_lex());
+    /// assert_eq!(packed_array, [3, 2]);
+    /// ```
+    pub fn decrement_lex(&mut self) -> bool {
+        for i in (0..LEN).rev() {
+            let digit = self.get(i);
+            if digit > 0 {
+                self.set(i, digit - 1);
+                return true;
+            }
+            self.set(i, Self::MAX_VALUE);
+        }
+        false
+    }
+}
+
+/// An iterator over every possible [`PackedIntegerArray`] of a shape in lexicographic order.
+///
+/// Use [`PackedIntegerArray::enumerate_all()`] to construct a new instance.
+pub struct EnumerateAll<const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    next: Option<PackedIntegerArray<BITS, LEN>>
+}
+
+impl <const BITS: u8, const LEN: usize> Iterator for EnumerateAll<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Item = PackedIntegerArray<BITS, LEN>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        let next = self.next.as_mut()?;
+        let current = PackedIntegerArray {
+            content: next.content
+        };
+        if !next.increment_lex() {
+            self.next = None;
+        }
+        Some(current)
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> FusedIterator for EnumerateAll<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    const ENUMERABLE: () = if (BITS as usize) * LEN > MAX_ENUMERATED_BITS {
+        panic!("too many bits in total to enumerate every possible array");
+    };
+
+    /// Returns an iterator over every possible array of this shape in lexicographic order, starting at all zeroes, see
+    /// [`PackedIntegerArray::increment_lex()`].
+    ///
+    /// This is meant for exhaustively testing algorithms over small packed states.
+    ///
+    /// ```rust
+    /// let all: Vec<_> = pia::PackedIntegerArray::<2, 3>::enumerate_all().collect();
+    ///
+    /// assert_eq!(all.len(), 64);
+    /// assert_eq!(all[0], [0, 0, 0]);
+    /// assert_eq!(all[6], [0, 1, 2]);
+    /// assert_eq!(all[63], [3, 3, 3]);
+    /// ```
+    ///
+    /// Note: arrays of more than 24 bits in total are rejected at compile time, since there are too many to enumerate.
+    /// ```compile_fail
+    /// let all = pia::PackedIntegerArray::<5, 5>::enumerate_all();
+    /// ```
+    pub fn enumerate_all() -> EnumerateAll<BITS, LEN> {
+        // Evaluating the constant is what rejects shapes that are too large at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::ENUMERABLE;
+
+        EnumerateAll {
+            next: Some(Self::new())
+        }
+    }
+}
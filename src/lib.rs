@@ -1,6 +1,7 @@
 #![allow(incomplete_features)]
 #![feature(const_generics)]
 #![feature(const_evaluatable_checked)]
+#![feature(const_panic)]
 
 #![allow(unused_parens)]
 
@@ -16,8 +17,12 @@
 //!
 //! After that, use the array just like any other array. Items can be set using [`PackedIntegerArray::set()`],
 //! items can be queried using [`PackedIntegerArray::get()`], and items can be reset back to 0 using [`PackedIntegerArray::clear()`].
+//!
+//! Setting a value that does not fit in the bits of an item truncates the overflowing bits and logs a warning. Enable the
+//! `silent-truncate` feature to compile the check and the warning out entirely.
 
 extern crate log;
+#[cfg(not(feature = "silent-truncate"))]
 use log::warn;
 
 #[cfg(feature = "serde")]
@@ -25,6 +30,70 @@ extern crate serde;
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
+#[cfg(feature = "rand")]
+extern crate rand;
+
+mod error;
+pub use error::Error;
+mod cursor;
+pub use cursor::Cursor;
+mod frozen;
+pub use frozen::FrozenPackedArray;
+mod delta;
+mod bloom;
+pub use bloom::PackedBloom;
+mod sketch;
+pub use sketch::CountingSketch;
+mod small_set;
+pub use small_set::PackedSmallSet;
+mod rank_select;
+pub use rank_select::{RankSelectIndex, ValueIndex};
+mod prefix_sums;
+pub use prefix_sums::PrefixSums;
+mod range_query;
+pub use range_query::RangeQuery;
+mod heap;
+pub use heap::PackedBinaryHeap;
+mod clock;
+pub use clock::ClockCursor;
+mod refcount;
+mod transaction;
+pub use transaction::Transaction;
+mod validated;
+pub use validated::ValidatedPackedArray;
+mod slice;
+pub use slice::{ChunksMut, IterMut, PackedElementMut, PackedSliceMut};
+mod bit_order;
+pub use bit_order::BitOrder;
+mod interleave;
+mod reinterpret;
+mod typed;
+mod element;
+pub use element::{PackedElement, TypedPackedArray};
+mod layout;
+pub use layout::{layout, Layout, LAYOUT_VERSION};
+mod raw;
+mod volatile;
+pub use volatile::VolatilePackedArray;
+mod quad_state;
+pub use quad_state::QuadStateArray;
+mod nibble;
+pub use nibble::NibbleArray;
+mod digits;
+mod radix;
+pub use radix::RadixNumber;
+mod lex;
+pub use lex::EnumerateAll;
+mod search;
+mod runs;
+mod window;
+mod bitplane;
+pub use bitplane::transpose8x8;
+#[cfg(feature = "store")]
+mod store;
+#[cfg(feature = "store")]
+pub use store::PackedStore;
+
 /// A helper function to determine the minimum amount of `u8`s that are needed in order to house `size` amount of items each of
 /// `bits` amount of bits.
 ///
@@ -32,10 +101,101 @@ use serde::{Serialize, Deserialize};
 /// // 4 items each with 3 bits per item is 12 bits in total which is housed by a minimum of 2 `u8`s
 /// assert_eq!(pia::get_array_length(3, 4), 2);
 /// ```
+///
+/// Note: if the total amount of bits does not fit in a `usize` this panics, which turns into a compile time error when the length
+/// is computed for the type of a [`PackedIntegerArray`].
+/// ```compile_fail
+/// // 8 * usize::MAX bits can not be addressed, so this array type is rejected at compile time
+/// let packed_array = pia::PackedIntegerArray::<8, { usize::MAX }>::new();
+/// ```
 pub const fn get_array_length(bits: u8, size: usize) -> usize {
-    (((bits as usize) * size) + (u8::BITS as usize) - 1) / (u8::BITS as usize)
+    match checked_get_array_length(bits, size) {
+        Some(length) => length,
+        None => panic!("the total amount of bits in the packed array overflows usize")
+    }
 }
 
+/// A checked version of [`get_array_length()`] that returns `None` instead of panicking if the total amount of bits does not fit
+/// in a `usize`.
+///
+/// ```rust
+/// assert_eq!(pia::checked_get_array_length(3, 4), Some(2));
+/// assert_eq!(pia::checked_get_array_length(8, usize::MAX), None);
+/// ```
+pub const fn checked_get_array_length(bits: u8, size: usize) -> Option<usize> {
+    match (bits as usize).checked_mul(size) {
+        Some(total_bits) => match total_bits.checked_add((u8::BITS as usize) - 1) {
+            Some(rounded_bits) => Some(rounded_bits / (u8::BITS as usize)),
+            None => None
+        },
+        None => None
+    }
+}
+
+/// A helper function to determine the offset in bits of the item at `index` from the start of an array of items each of `bits`
+/// amount of bits.
+///
+/// ```rust
+/// // The item at index 3 of an array with 3 bits per item starts 9 bits in, which is bit 1 of byte 1
+/// assert_eq!(pia::bit_offset_of(3, 3), 9);
+/// ```
+pub const fn bit_offset_of(bits: u8, index: usize) -> usize {
+    (bits as usize) * index
+}
+
+/// A helper function to determine the range of `u8`s that house the item at `index` in an array of items each of `bits` amount of
+/// bits.
+///
+/// ```rust
+/// // The item at index 2 of an array with 3 bits per item spans bits 6 to 8, so it is split over bytes 0 and 1
+/// assert_eq!(pia::byte_range_of(3, 2), 0..2);
+/// assert_eq!(pia::byte_range_of(3, 3), 1..2);
+/// ```
+pub const fn byte_range_of(bits: u8, index: usize) -> std::ops::Range<usize> {
+    let start = bit_offset_of(bits, index) / (u8::BITS as usize);
+    let end = get_array_length(bits, index + 1);
+    start..end
+}
+
+/// A helper function to determine the amount of unused bits at the end of the last `u8` of an array of `size` amount of items each
+/// of `bits` amount of bits.
+///
+/// ```rust
+/// // 4 items each with 3 bits per item use 12 of the 16 bits of 2 `u8`s
+/// assert_eq!(pia::padding_bits(3, 4), 4);
+/// ```
+pub const fn padding_bits(bits: u8, size: usize) -> usize {
+    get_array_length(bits, size) * (u8::BITS as usize) - bit_offset_of(bits, size)
+}
+
+/// A helper function to determine the maximum amount of items each of `bits` amount of bits that fit in `bytes` amount of `u8`s.
+///
+/// ```rust
+/// // 2 `u8`s hold 16 bits, which is enough for 5 items of 3 bits each
+/// assert_eq!(pia::elements_fitting_in(3, 2), 5);
+/// ```
+pub const fn elements_fitting_in(bits: u8, bytes: usize) -> usize {
+    (bytes * (u8::BITS as usize)) / (bits as usize)
+}
+
+/// Logs a warning if `value` is greater than the maximum value representable with `bits` bits, in which case the overflowing bits
+/// are about to be truncated.
+#[cfg(not(feature = "silent-truncate"))]
+#[inline]
+pub(crate) fn warn_if_truncated(value: u8, bits: u8) {
+    // The same as `MAX_VALUE`, which folds to a constant since `bits` is always the `BITS` of an array
+    let max = ((1u16 << bits) - 1) as u8;
+    if value > max {
+        warn!("Warning: input value {} is greater than the maximum value {} for {} bits. This may cause unintended functionality.", value, max, bits);
+    }
+}
+
+/// With the `silent-truncate` feature overflowing bits are truncated without a warning, so that setting items has no overhead
+/// beyond the write itself.
+#[cfg(feature = "silent-truncate")]
+#[inline(always)]
+pub(crate) fn warn_if_truncated(_value: u8, _bits: u8) {}
+
 /// A wrapped array that bit packs `LEN` amount of items each of `BITS` amount of bits into an array of `u8`s.
 ///
 /// Use [`PackedIntegerArray::new()`] to construct a new instance.
@@ -48,6 +208,9 @@ pub const fn get_array_length(bits: u8, size: usize) -> usize {
 /// packed_array.set(3, 7);
 /// assert_eq!(packed_array.get(3), 7);
 /// ```
+///
+/// Note: the array is guaranteed to be `#[repr(transparent)]` over its packed content, so it has the size and alignment of
+/// `[u8; get_array_length(BITS, LEN)]`. See [`LAYOUT_VERSION`] for sharing the content between processes.
 #[derive(Debug, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(transparent)]
@@ -72,6 +235,78 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
         Self::default()
     }
 
+    /// The amount of items in the array, the same as `LEN`.
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::LEN, 9);
+    /// ```
+    pub const LEN: usize = LEN;
+
+    /// The amount of bits per item in the array, the same as `BITS`.
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::BITS, 3);
+    /// ```
+    pub const BITS: u8 = BITS;
+
+    /// The amount of `u8`s the items are packed into, see [`get_array_length()`].
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::BYTE_LEN, 4);
+    /// ```
+    pub const BYTE_LEN: usize = get_array_length(BITS, LEN);
+
+    /// The amount of bits used by the items, which is [`PackedIntegerArray::BYTE_LEN`] bytes minus the padding bits at the end
+    /// of the last byte.
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::BITS_USED, 27);
+    /// assert_eq!(pia::PackedIntegerArray::<3, 9>::BYTE_LEN * 8 - pia::padding_bits(3, 9), 27);
+    /// ```
+    pub const BITS_USED: usize = bit_offset_of(BITS, LEN);
+
+    /// The greatest value an item can hold, which is `2^BITS - 1`.
+    ///
+    /// Values passed to [`PackedIntegerArray::set()`] that are greater than this are truncated, so clamping to it keeps the
+    /// greatest representable value instead.
+    ///
+    /// ```rust
+    /// type Light = pia::PackedIntegerArray<4, 16>;
+    ///
+    /// let mut light = Light::new();
+    /// light.set(0, u8::min(20, Light::MAX_VALUE));
+    ///
+    /// assert_eq!(light.get(0), 15);
+    /// ```
+    pub const MAX_VALUE: u8 = ((1u16 << BITS) - 1) as u8;
+
+    /// Returns the amount of items in the array, see [`PackedIntegerArray::LEN`].
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// let description = format!("{} items of {} bits in {} bytes", packed_array.len(), packed_array.bits(), packed_array.byte_len());
+    /// assert_eq!(description, "9 items of 3 bits in 4 bytes");
+    /// ```
+    pub const fn len(&self) -> usize {
+        LEN
+    }
+
+    /// Returns `true` if the array holds no items.
+    pub const fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+
+    /// Returns the amount of bits per item in the array, see [`PackedIntegerArray::BITS`].
+    pub const fn bits(&self) -> u8 {
+        BITS
+    }
+
+    /// Returns the amount of `u8`s the items are packed into, see [`PackedIntegerArray::BYTE_LEN`].
+    pub const fn byte_len(&self) -> usize {
+        Self::BYTE_LEN
+    }
+
     /// Returns the packed integer value at the given index in the array.
     ///
     /// `index` references the index of the item in the array before bit-packing.
@@ -86,6 +321,7 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
     /// ```
     ///
     /// Note: just like a normal array, if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
     pub fn get(&self, index: usize) -> u8 {
         if index >= LEN {
             panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
@@ -94,6 +330,30 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
         let start_byte = (index * (BITS as usize)) / (u8::BITS as usize); // The index of the byte that contains the start of the item
         let start_bit = (index * (BITS as usize)) - (start_byte * (u8::BITS as usize)); // The first bit on that byte containing the start of the item
 
+        self.get_at(start_byte, start_bit)
+    }
+
+    /// Returns the packed integer value at the given index in the array, or `None` if the index is outside of the array bounds.
+    ///
+    /// This is the non-panicking version of [`PackedIntegerArray::get()`], just like [`slice::get()`] is for indexing a slice.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(8, 5);
+    ///
+    /// assert_eq!(packed_array.try_get(8), Some(5));
+    /// assert_eq!(packed_array.try_get(9), None);
+    /// ```
+    pub fn try_get(&self, index: usize) -> Option<u8> {
+        if index < LEN {
+            Some(self.get(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the packed integer value that starts at bit `start_bit` of byte `start_byte` in the packed content.
+    pub(crate) fn get_at(&self, start_byte: usize, start_bit: usize) -> u8 {
         let mut result = ((self.content[start_byte] << start_bit) >> ((u8::BITS as usize) - (BITS as usize)));
         if start_bit + (BITS as usize) > (u8::BITS as usize) {
             result |= (self.content[start_byte + 1] >> ((u8::BITS as usize * 2) - (start_bit + (BITS as usize))));
@@ -102,6 +362,20 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
         result
     }
 
+    /// The same as [`PackedIntegerArray::get_at()`] but without bounds checks on the packed content.
+    ///
+    /// # Safety
+    ///
+    /// Every byte the item spans has to be inside of the packed content.
+    unsafe fn get_at_unchecked(&self, start_byte: usize, start_bit: usize) -> u8 {
+        let mut result = ((*self.content.get_unchecked(start_byte) << start_bit) >> ((u8::BITS as usize) - (BITS as usize)));
+        if start_bit + (BITS as usize) > (u8::BITS as usize) {
+            result |= (*self.content.get_unchecked(start_byte + 1) >> ((u8::BITS as usize * 2) - (start_bit + (BITS as usize))));
+        }
+
+        result
+    }
+
     /// Sets the packed integer value at `index` in the array to `value`
     ///
     /// `index` references the index of the item in the array before bit-packing.
@@ -131,11 +405,9 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
     /// // When the values are returned they are the same because any bits greater than 3 are truncated
     /// assert_eq!(packed_array.get(2), packed_array.get(4));
     /// ```
+    #[track_caller]
     pub fn set(&mut self, index: usize, value: u8) {
-        let max = usize::pow(2, BITS as u32);
-        if value as usize >= max {
-            warn!("Warning: input value {} is greater than the maximum value {} for {} bits. This may cause unintended functionality.", value, max - 1, BITS);
-        }
+        warn_if_truncated(value, BITS);
 
         if index >= LEN {
             panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
@@ -144,6 +416,83 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
         let start_byte = (index * (BITS as usize)) / (u8::BITS as usize); // The index of the byte that contains the start of the item
         let start_bit = (index * (BITS as usize)) - (start_byte * (u8::BITS as usize)); // The first bit on that byte containing the start of the item
 
+        self.set_at(start_byte, start_bit, value);
+    }
+
+    /// Sets the packed integer value at `index` in the array to `value`, or returns an error and leaves the array untouched if
+    /// `index` is outside of the array bounds or `value` does not fit in `BITS` bits.
+    ///
+    /// This is the checked version of [`PackedIntegerArray::set()`], which panics on the former and truncates the latter.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// assert_eq!(packed_array.try_set(3, 7), Ok(()));
+    /// assert_eq!(packed_array.try_set(9, 7), Err(pia::Error::IndexOutOfBounds { index: 9, len: 9 }));
+    /// assert_eq!(packed_array.try_set(3, 8), Err(pia::Error::ValueOverflow { value: 8, bits: 3 }));
+    ///
+    /// assert_eq!(packed_array.get(3), 7);
+    /// ```
+    pub fn try_set(&mut self, index: usize, value: u8) -> Result<(), Error> {
+        if index >= LEN {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: LEN
+            });
+        }
+        if value > Self::MAX_VALUE {
+            return Err(Error::ValueOverflow {
+                value: value as u64,
+                bits: BITS
+            });
+        }
+
+        self.set(index, value);
+        Ok(())
+    }
+
+    /// Returns the packed integer value at `index` in the array without checking that `index` is inside of the array bounds.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(4, 6);
+    ///
+    /// assert_eq!(unsafe { packed_array.get_unchecked(4) }, 6);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Just like [`slice::get_unchecked()`], calling this with an `index` outside of the array bounds is undefined behavior even if
+    /// the returned value is not used.
+    pub unsafe fn get_unchecked(&self, index: usize) -> u8 {
+        let start_byte = (index * (BITS as usize)) / (u8::BITS as usize);
+        let start_bit = (index * (BITS as usize)) - (start_byte * (u8::BITS as usize));
+
+        self.get_at_unchecked(start_byte, start_bit)
+    }
+
+    /// Sets the packed integer value at `index` in the array to `value` without checking that `index` is inside of the array bounds
+    /// and without warning about overflowing bits of `value`, which are still truncated.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// unsafe { packed_array.set_unchecked(4, 6) };
+    ///
+    /// assert_eq!(packed_array.get(4), 6);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Just like [`slice::get_unchecked_mut()`], calling this with an `index` outside of the array bounds is undefined behavior.
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: u8) {
+        let start_byte = (index * (BITS as usize)) / (u8::BITS as usize);
+        let start_bit = (index * (BITS as usize)) - (start_byte * (u8::BITS as usize));
+
+        self.set_at_unchecked(start_byte, start_bit, value);
+    }
+
+    /// Sets the packed integer value that starts at bit `start_bit` of byte `start_byte` in the packed content to `value`.
+    pub(crate) fn set_at(&mut self, start_byte: usize, start_bit: usize, value: u8) {
         // Clear the current content
         if start_bit + (BITS as usize) > (u8::BITS as usize) {
             // If spread over multiple bytes
@@ -160,6 +509,28 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
         }
     }
 
+    /// The same as [`PackedIntegerArray::set_at()`] but without bounds checks on the packed content.
+    ///
+    /// # Safety
+    ///
+    /// Every byte the item spans has to be inside of the packed content.
+    unsafe fn set_at_unchecked(&mut self, start_byte: usize, start_bit: usize, value: u8) {
+        let first = self.content.get_unchecked_mut(start_byte);
+        if start_bit + (BITS as usize) > (u8::BITS as usize) {
+            // If spread over multiple bytes
+            let shift = (u8::BITS as usize * 2) - (start_bit + (BITS as usize));
+            *first ^= ((*first << start_bit) >> start_bit);
+            *first |= ((value << ((u8::BITS as usize) - (BITS as usize))) >> start_bit);
+
+            let second = self.content.get_unchecked_mut(start_byte + 1);
+            *second ^= (*second >> shift) << shift;
+            *second |= (value << shift);
+        } else {
+            *first ^= ((*first << start_bit) >> ((u8::BITS as usize) - (BITS as usize))) << ((u8::BITS as usize) - (BITS as usize) - start_bit);
+            *first |= ((value << ((u8::BITS as usize) - (BITS as usize))) >> start_bit);
+        }
+    }
+
     /// Sets the packed integer value at the given `index` in the array to 0
     ///
     /// `index` references the index of the item in the array before bit-packing.
@@ -176,6 +547,7 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
     /// ```
     ///
     /// Note: just like a normal array, if an item outside of the array bounds is set the program will panic.
+    #[track_caller]
     pub fn clear(&mut self, index: usize) {
 
         if index >= LEN {
@@ -195,21 +567,488 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
         }
     }
 
+    /// Sets every packed integer value in the array to `value`.
+    ///
+    /// The packed bytes of `value` repeat every [`PackedIntegerArray::aligned_chunk_size()`] items, so the repeating pattern is
+    /// computed once and written a whole byte at a time.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.fill(5);
+    ///
+    /// assert_eq!(packed_array, [5; 9]);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of `value` are truncated.
+    pub fn fill(&mut self, value: u8) {
+        warn_if_truncated(value, BITS);
+
+        // At most 8 items of at most 8 bits each before the pattern repeats
+        let pattern_len = Self::aligned_chunk_size() * (BITS as usize) / (u8::BITS as usize);
+        let mut pattern = [0u8; 8];
+        for bit in 0..(pattern_len * (u8::BITS as usize)) {
+            let item_bit = (value >> ((BITS as usize) - 1 - (bit % (BITS as usize)))) & 1;
+            pattern[bit / (u8::BITS as usize)] |= item_bit << ((u8::BITS as usize) - 1 - (bit % (u8::BITS as usize)));
+        }
+
+        for (i, byte) in self.content.iter_mut().enumerate() {
+            *byte = pattern[i % pattern_len];
+        }
+
+        // The padding bits at the end of the last byte are kept 0
+        let padding = padding_bits(BITS, LEN);
+        if let Some(last) = self.content.last_mut() {
+            *last &= ((u8::MAX as u16) << padding) as u8;
+        }
+    }
+
+    /// Sets every packed integer value in the array to 0 by zeroing the packed content as a whole.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.fill(7);
+    /// packed_array.clear_all();
+    ///
+    /// assert_eq!(packed_array, pia::PackedIntegerArray::<3, 9>::new());
+    /// ```
+    pub fn clear_all(&mut self) {
+        self.content = [0; get_array_length(BITS, LEN)];
+    }
+
+    /// Swaps the packed integer values at index `a` and index `b` in the array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(1, 6);
+    /// packed_array.set(7, 2);
+    ///
+    /// packed_array.swap(1, 7);
+    /// assert_eq!(packed_array, [0, 2, 0, 0, 0, 0, 0, 6, 0]);
+    /// ```
+    ///
+    /// Note: just like a normal array, if either item is outside of the array bounds the program will panic.
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let value_a = self.get(a);
+        let value_b = self.get(b);
+        self.set(a, value_b);
+        self.set(b, value_a);
+    }
+
+    /// Sets the packed integer values starting at index `offset` in the array to `values`, in one pass over the packed content.
+    ///
+    /// Once an item starts on a byte boundary the values are packed a run of [`PackedIntegerArray::aligned_chunk_size()`] items
+    /// at a time and written out as whole bytes, so only the items before that boundary and the ones after the last whole run are
+    /// set one at a time.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 16>::new();
+    /// packed_array.set_from_slice(3, &[1, 2, 3, 4, 5, 6, 7, 1, 2, 3]);
+    ///
+    /// assert_eq!(packed_array, [0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 1, 2, 3, 0, 0, 0]);
+    /// ```
+    ///
+    /// Note: if `values` does not fit in the array starting at `offset` the program will panic.
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of the values are truncated.
+    #[track_caller]
+    pub fn set_from_slice(&mut self, offset: usize, values: &[u8]) {
+        if offset > LEN || values.len() > LEN - offset {
+            panic!("range end index {} out of range for packed array of length {}", offset.saturating_add(values.len()), LEN);
+        }
+
+        let mut start_byte = bit_offset_of(BITS, offset) / (u8::BITS as usize); // The index of the byte that contains the start of the next item
+        let mut start_bit = bit_offset_of(BITS, offset) % (u8::BITS as usize); // The first bit on that byte containing the start of the next item
+
+        // Items one at a time until the next item starts on a byte boundary
+        let mut values = values;
+        while start_bit != 0 && !values.is_empty() {
+            warn_if_truncated(values[0], BITS);
+            self.set_at(start_byte, start_bit, values[0]);
+            start_bit += BITS as usize;
+            start_byte += start_bit / (u8::BITS as usize);
+            start_bit %= (u8::BITS as usize);
+            values = &values[1..];
+        }
+
+        // Runs of items that fill whole bytes, so the next item is always back on a byte boundary
+        let mut chunks = values.chunks_exact(Self::aligned_chunk_size());
+        for chunk in &mut chunks {
+            let mut buffer: u16 = 0;
+            let mut buffered_bits = 0;
+            for &value in chunk {
+                warn_if_truncated(value, BITS);
+
+                buffer = (buffer << BITS) | (value & Self::MAX_VALUE) as u16;
+                buffered_bits += BITS as usize;
+                if buffered_bits >= (u8::BITS as usize) {
+                    buffered_bits -= (u8::BITS as usize);
+                    self.content[start_byte] = (buffer >> buffered_bits) as u8;
+                    buffer &= (1 << buffered_bits) - 1;
+                    start_byte += 1;
+                }
+            }
+        }
+
+        // The items left over after the last whole run
+        for &value in chunks.remainder() {
+            warn_if_truncated(value, BITS);
+            self.set_at(start_byte, start_bit, value);
+            start_bit += BITS as usize;
+            start_byte += start_bit / (u8::BITS as usize);
+            start_bit %= (u8::BITS as usize);
+        }
+    }
+
     /// Unpacks the packed array into an array of `u8`s
     ///
+    /// The packed array is only borrowed, so it can still be used afterwards.
+    ///
     /// ```rust
     /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
     /// packed_array.set(2, 4);
     /// packed_array.set(4, 5);
     /// assert_eq!(packed_array.unpack(), [0, 0, 4, 0, 5, 0, 0, 0, 0]);
+    ///
+    /// packed_array.set(6, 1);
+    /// assert_eq!(packed_array.unpack(), [0, 0, 4, 0, 5, 0, 1, 0, 0]);
     /// ```
     ///
-    pub fn unpack(self) -> [u8; LEN] {
+    pub fn unpack(&self) -> [u8; LEN] {
         let mut items: [u8; LEN] = [0; LEN];
+        self.unpack_into(&mut items);
+        items
+    }
+
+    /// Unpacks the packed array into `items`, reusing a buffer instead of constructing a new array of `u8`s every time.
+    ///
+    /// ```rust
+    /// let mut buffer = [0; 9];
+    ///
+    /// let packed_array = pia::PackedIntegerArray::<3, 9>::splat(2);
+    /// packed_array.unpack_into(&mut buffer);
+    /// assert_eq!(buffer, [2; 9]);
+    /// ```
+    pub fn unpack_into(&self, items: &mut [u8; LEN]) {
+        for (i, item) in items.iter_mut().enumerate() {
+            *item = self.get(i);
+        }
+    }
+
+    /// Unpacks the packed array into a slice of any type that can be converted from a `u8`, such as a `u16`, `u32` or `f32` buffer.
+    ///
+    /// ```rust
+    /// let mut samples = [0.0f32; 4];
+    ///
+    /// let packed_array = pia::PackedIntegerArray::<3, 4>::from_unpacked([1, 2, 3, 7]);
+    /// packed_array.unpack_into_slice(&mut samples);
+    /// assert_eq!(samples, [1.0, 2.0, 3.0, 7.0]);
+    /// ```
+    ///
+    /// Note: just like [`slice::copy_from_slice()`], if the length of `items` is not `LEN` the program will panic.
+    #[track_caller]
+    pub fn unpack_into_slice<T: From<u8>>(&self, items: &mut [T]) {
+        if items.len() != LEN {
+            panic!("source slice length ({}) does not match destination slice length ({})", LEN, items.len());
+        }
+
+        for (item, value) in items.iter_mut().zip(self.iter()) {
+            *item = T::from(value);
+        }
+    }
+
+    /// Unpacks the packed array into a newly allocated [`Vec`] of `u8`s.
+    ///
+    /// To unpack the values lazily without allocating, use [`PackedIntegerArray::unpack_iter()`] instead.
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 4>::from_unpacked([1, 2, 3, 4]);
+    /// assert_eq!(packed_array.to_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.iter().collect()
+    }
+
+    /// Returns an iterator over every unpacked value in the array that borrows the array instead of consuming it.
+    ///
+    /// Values are unpacked one at a time as the iterator advances, see [`PackedIntegerArray::unpack_iter()`].
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 4>::from_unpacked([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(packed_array.iter().sum::<u8>(), 10);
+    /// assert_eq!(packed_array.iter().max(), Some(4));
+    ///
+    /// for item in &packed_array {
+    ///     println!("{}", item);
+    /// }
+    /// ```
+    pub fn iter(&self) -> Iter<'_, BITS, LEN> {
+        Iter {
+            index: 0,
+            back: LEN,
+            array: self
+        }
+    }
+
+    /// Returns an iterator that unpacks the values of the array one at a time as it advances, the lazy counterpart of
+    /// [`PackedIntegerArray::unpack()`] and [`PackedIntegerArray::to_vec()`]. This is the same iterator as
+    /// [`PackedIntegerArray::iter()`].
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 4>::from_unpacked([1, 2, 3, 4]);
+    ///
+    /// let mut values = packed_array.unpack_iter();
+    /// assert_eq!(values.len(), 4);
+    /// assert_eq!(values.next(), Some(1));
+    /// assert_eq!(values.next_back(), Some(4));
+    /// ```
+    pub fn unpack_iter(&self) -> Iter<'_, BITS, LEN> {
+        self.iter()
+    }
+
+    /// Returns an iterator over the index and unpacked value of every item in the array.
+    ///
+    /// The byte and bit offset of the current item are advanced incrementally instead of being recomputed from the index for every
+    /// item, just like a [`Cursor`] does.
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 4>::from_unpacked([0, 5, 0, 2]);
+    ///
+    /// let set: Vec<_> = packed_array.indexed_iter().filter(|&(_, value)| value != 0).collect();
+    /// assert_eq!(set, [(1, 5), (3, 2)]);
+    /// ```
+    pub fn indexed_iter(&self) -> IndexedIter<'_, BITS, LEN> {
+        IndexedIter {
+            index: 0,
+            start_byte: 0,
+            start_bit: 0,
+            array: self
+        }
+    }
+
+    /// Packs an array of `u8`s into a new packed array, the inverse of [`PackedIntegerArray::unpack()`].
+    ///
+    /// The items are shifted into a small bit buffer that is written out a whole byte at a time, so every byte of the packed
+    /// content is written once.
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 9>::from_unpacked([0, 0, 4, 0, 5, 0, 0, 0, 0]);
+    /// assert_eq!(packed_array.get(2), 4);
+    /// assert_eq!(packed_array.unpack(), [0, 0, 4, 0, 5, 0, 0, 0, 0]);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of the values are truncated.
+    pub fn from_unpacked(items: [u8; LEN]) -> Self {
+        let mut array = Self::new();
+
+        let mut buffer: u16 = 0;
+        let mut buffered_bits = 0;
+        let mut byte = 0;
+        for &value in items.iter() {
+            warn_if_truncated(value, BITS);
+
+            buffer = (buffer << BITS) | (value & Self::MAX_VALUE) as u16;
+            buffered_bits += BITS as usize;
+            if buffered_bits >= (u8::BITS as usize) {
+                buffered_bits -= (u8::BITS as usize);
+                array.content[byte] = (buffer >> buffered_bits) as u8;
+                buffer &= (1 << buffered_bits) - 1;
+                byte += 1;
+            }
+        }
+        if buffered_bits > 0 {
+            array.content[byte] = (buffer << ((u8::BITS as usize) - buffered_bits)) as u8;
+        }
+
+        array
+    }
+
+    /// Constructs a new packed array where every item is the value returned by `f` for its index, just like
+    /// [`core::array::from_fn()`].
+    ///
+    /// ```rust
+    /// // A gradient from 0 to 7
+    /// let gradient = pia::PackedIntegerArray::<3, 16>::from_fn(|i| (i / 2) as u8);
+    ///
+    /// assert_eq!(gradient.get(0), 0);
+    /// assert_eq!(gradient.get(9), 4);
+    /// assert_eq!(gradient.get(15), 7);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of the returned values are truncated.
+    pub fn from_fn<F: FnMut(usize) -> u8>(mut f: F) -> Self {
+        let mut array = Self::new();
         for i in 0..LEN {
-            items[i] = self.get(i)
+            array.set(i, f(i));
+        }
+        array
+    }
+
+    /// Constructs a new packed array where every item is the value returned by `f` for its index, or returns the first error
+    /// returned by `f`. Items after the one that failed are not visited.
+    ///
+    /// ```rust
+    /// let parsed = pia::PackedIntegerArray::<3, 4>::try_from_fn(|i| "1234".as_bytes()[i].checked_sub(b'0').ok_or(i));
+    /// assert_eq!(parsed, Ok(pia::PackedIntegerArray::from([1, 2, 3, 4])));
+    ///
+    /// let failed = pia::PackedIntegerArray::<3, 4>::try_from_fn(|i| if i < 2 { Ok(0) } else { Err(i) });
+    /// assert_eq!(failed, Err(2));
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of the returned values are truncated.
+    pub fn try_from_fn<E, F: FnMut(usize) -> Result<u8, E>>(mut f: F) -> Result<Self, E> {
+        let mut array = Self::new();
+        for i in 0..LEN {
+            array.set(i, f(i)?);
+        }
+        Ok(array)
+    }
+
+    /// Packs a slice of any unsigned integer type, or returns an error if the slice does not hold exactly `LEN` values or a value
+    /// does not fit in `BITS` bits. This is the checked inverse of [`PackedIntegerArray::unpack_into_slice()`].
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<5, 4>::try_pack_from(&[1u16, 2, 30, 31]);
+    /// assert_eq!(packed_array.unwrap(), [1, 2, 30, 31]);
+    ///
+    /// let too_short = pia::PackedIntegerArray::<5, 4>::try_pack_from(&[1u32, 2, 3]);
+    /// assert_eq!(too_short.unwrap_err(), pia::Error::LengthMismatch { expected: 4, found: 3 });
+    ///
+    /// let too_wide = pia::PackedIntegerArray::<5, 4>::try_pack_from(&[1u16, 2, 3, 300]);
+    /// assert_eq!(too_wide.unwrap_err(), pia::Error::ValueOverflow { value: 300, bits: 5 });
+    /// ```
+    pub fn try_pack_from<T: Copy + Into<u64>>(items: &[T]) -> Result<Self, Error> {
+        if items.len() != LEN {
+            return Err(Error::LengthMismatch {
+                expected: LEN,
+                found: items.len()
+            });
+        }
+
+        let mut array = Self::new();
+        for (i, &item) in items.iter().enumerate() {
+            let value: u64 = item.into();
+            if value > Self::MAX_VALUE as u64 {
+                return Err(Error::ValueOverflow {
+                    value,
+                    bits: BITS
+                });
+            }
+            array.set(i, value as u8);
+        }
+        Ok(array)
+    }
+
+    /// Constructs a new packed array with every item set to `value`, see [`PackedIntegerArray::fill()`].
+    ///
+    /// ```rust
+    /// let packed_array = pia::PackedIntegerArray::<3, 9>::splat(6);
+    /// assert_eq!(packed_array, [6; 9]);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of `value` are truncated.
+    pub fn splat(value: u8) -> Self {
+        let mut array = Self::new();
+        array.fill(value);
+        array
+    }
+
+    /// Merges `other` into this array item by item, replacing every item with the result of `resolve(own_value, other_value)`.
+    ///
+    /// As long as `resolve` is commutative, associative, and idempotent, replicas that merge each other's state in any order
+    /// converge to the same array.
+    ///
+    /// ```rust
+    /// let mut replica_a = pia::PackedIntegerArray::<3, 4>::new();
+    /// replica_a.set(0, 0b101);
+    ///
+    /// let mut replica_b = pia::PackedIntegerArray::<3, 4>::new();
+    /// replica_b.set(0, 0b011);
+    /// replica_b.set(1, 0b001);
+    ///
+    /// // Merge the items as sets of flags
+    /// replica_a.merge_with(&replica_b, |a, b| a | b);
+    /// assert_eq!(replica_a, [0b111, 0b001, 0, 0]);
+    /// ```
+    pub fn merge_with<F: FnMut(u8, u8) -> u8>(&mut self, other: &Self, mut resolve: F) {
+        for i in 0..LEN {
+            let value = resolve(self.get(i), other.get(i));
+            self.set(i, value);
+        }
+    }
+
+    /// Merges `other` into this array by keeping the greater of the two values for every item.
+    ///
+    /// ```rust
+    /// // Per item version counters of two replicas
+    /// let mut replica_a = pia::PackedIntegerArray::<3, 4>::new();
+    /// replica_a.set(0, 4);
+    /// replica_a.set(1, 1);
+    ///
+    /// let mut replica_b = pia::PackedIntegerArray::<3, 4>::new();
+    /// replica_b.set(1, 6);
+    ///
+    /// replica_a.merge_max(&replica_b);
+    /// assert_eq!(replica_a, [4, 6, 0, 0]);
+    /// ```
+    pub fn merge_max(&mut self, other: &Self) {
+        self.merge_with(other, u8::max)
+    }
+
+    /// Returns the smallest amount of bits per item that can hold every value currently in the array, which is never less than 1.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<8, 4>::new();
+    /// assert_eq!(packed_array.suggest_bits(), 1);
+    ///
+    /// packed_array.set(2, 5);
+    /// assert_eq!(packed_array.suggest_bits(), 3);
+    /// ```
+    pub fn suggest_bits(&self) -> u8 {
+        let max = (0..LEN).map(|i| self.get(i)).max().unwrap_or(0);
+        u8::max(1, (u8::BITS - max.leading_zeros()) as u8)
+    }
+
+    /// Returns `true` if every value currently in the array can be represented with `bits` bits.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<8, 4>::new();
+    /// packed_array.set(2, 5);
+    ///
+    /// assert!(packed_array.can_narrow_to(3));
+    /// assert!(!packed_array.can_narrow_to(2));
+    /// ```
+    pub fn can_narrow_to(&self, bits: u8) -> bool {
+        bits >= (u8::BITS as u8) || (0..LEN).all(|i| self.get(i) >> bits == 0)
+    }
+
+    /// Replaces every item with the entry of `table` at the index of its current value, in a single pass over the array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<2, 4>::new();
+    /// packed_array.set(0, 1);
+    /// packed_array.set(1, 3);
+    ///
+    /// // Swap palette entries 1 and 3
+    /// packed_array.remap(&[0, 3, 2, 1]);
+    /// assert_eq!(packed_array, [3, 1, 0, 0]);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of the entries of `table` are truncated.
+    pub fn remap(&mut self, table: &[u8; 1 << BITS])
+    where [u8; 1 << BITS]: Sized {
+        let mut start_byte = 0;
+        let mut start_bit = 0;
+        for _ in 0..LEN {
+            let value = table[self.get_at(start_byte, start_bit) as usize];
+            warn_if_truncated(value, BITS);
+            self.set_at(start_byte, start_bit, value);
+
+            start_bit += BITS as usize;
+            start_byte += start_bit / (u8::BITS as usize);
+            start_bit %= (u8::BITS as usize);
         }
-        items
     }
 
 }
@@ -258,11 +1097,124 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
     fn into_iter(self) -> <Self as IntoIterator>::IntoIter {
         PackedIntegerArrayIterator {
             index: 0,
+            back: LEN,
             array: self
         }
     }
 }
 
+use std::iter::FromIterator;
+/// Collects up to `LEN` values into a packed array in order.
+///
+/// If the iterator runs out early the remaining items are left as 0, and if it yields more than `LEN` values the extra values are
+/// never taken from it. Use `PackedIntegerArray::try_from()` on a slice to reject input of the wrong length instead.
+///
+/// ```rust
+/// let packed_array: pia::PackedIntegerArray<3, 4> = (1..=3).collect();
+/// assert_eq!(packed_array, [1, 2, 3, 0]);
+///
+/// let squares: pia::PackedIntegerArray<8, 4> = (1..).map(|i: u8| i * i).collect();
+/// assert_eq!(squares, [1, 4, 9, 16]);
+/// ```
+///
+/// Note: just like [`PackedIntegerArray::set()`], overflowing bits of the values are truncated.
+impl <const BITS: u8, const LEN: usize> FromIterator<u8> for PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut array = Self::new();
+        for (i, value) in iter.into_iter().take(LEN).enumerate() {
+            array.set(i, value);
+        }
+        array
+    }
+}
+
+/// Every `u8` value in order, so that [`Index`] can hand out a reference to an unpacked value.
+static VALUES: [u8; 256] = {
+    let mut values = [0; 256];
+    let mut i = 0;
+    while i < values.len() {
+        values[i] = i as u8;
+        i += 1;
+    }
+    values
+};
+
+use std::ops::Index;
+impl <const BITS: u8, const LEN: usize> Index<usize> for PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Output = u8;
+
+    /// Returns a reference to the packed integer value at `index` in the array, see [`PackedIntegerArray::get()`].
+    ///
+    /// Since the value is packed it can not be referenced in place. The returned reference points to an equal value in a static
+    /// table instead, so writing through indexing is not possible; use [`PackedIntegerArray::set()`] for that.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(3, 7);
+    ///
+    /// assert_eq!(packed_array[3], 7);
+    /// assert_eq!(packed_array[3] + packed_array[4], 7);
+    /// ```
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    fn index(&self, index: usize) -> &u8 {
+        &VALUES[self.get(index) as usize]
+    }
+}
+
+use std::convert::From;
+impl <const BITS: u8, const LEN: usize> From<[u8; LEN]> for PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Packs an array of `u8`s, see [`PackedIntegerArray::from_unpacked()`].
+    ///
+    /// ```rust
+    /// let packed_array: pia::PackedIntegerArray<3, 4> = [1, 2, 3, 4].into();
+    /// assert_eq!(packed_array, [1, 2, 3, 4]);
+    /// ```
+    fn from(items: [u8; LEN]) -> Self {
+        Self::from_unpacked(items)
+    }
+}
+
+use std::convert::TryFrom;
+impl <const BITS: u8, const LEN: usize> TryFrom<&[u8]> for PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Error = Error;
+
+    /// Packs a slice of `u8`s, or returns an error if the slice does not hold exactly `LEN` values or a value does not fit in
+    /// `BITS` bits.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    ///
+    /// let packed_array = pia::PackedIntegerArray::<3, 4>::try_from(&[1, 2, 3, 4][..]);
+    /// assert_eq!(packed_array.unwrap(), [1, 2, 3, 4]);
+    ///
+    /// let too_short = pia::PackedIntegerArray::<3, 4>::try_from(&[1, 2, 3][..]);
+    /// assert_eq!(too_short.unwrap_err(), pia::Error::LengthMismatch { expected: 4, found: 3 });
+    ///
+    /// let too_wide = pia::PackedIntegerArray::<3, 4>::try_from(&[1, 2, 3, 8][..]);
+    /// assert_eq!(too_wide.unwrap_err(), pia::Error::ValueOverflow { value: 8, bits: 3 });
+    /// ```
+    fn try_from(items: &[u8]) -> Result<Self, Error> {
+        if items.len() != LEN {
+            return Err(Error::LengthMismatch {
+                expected: LEN,
+                found: items.len()
+            });
+        }
+
+        let mut array = Self::new();
+        for (i, &value) in items.iter().enumerate() {
+            array.try_set(i, value)?;
+        }
+        Ok(array)
+    }
+}
+
 use std::cmp::PartialEq;
 impl <const BITS: u8, const LEN: usize> PartialEq<[u8; LEN]> for PackedIntegerArray<BITS, LEN>
 where [u8; get_array_length(BITS, LEN)]: Sized {
@@ -291,7 +1243,17 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
     }
 }
 
-use std::iter::Iterator;
+impl <'a, const BITS: u8, const LEN: usize> IntoIterator for &'a PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Item = u8;
+    type IntoIter = Iter<'a, BITS, LEN>;
+
+    fn into_iter(self) -> <Self as IntoIterator>::IntoIter {
+        self.iter()
+    }
+}
+
+use std::iter::{FusedIterator, Iterator};
 /// A simple iterator that moves over every unpacked value in a [`PackedIntegerArray`].
 ///
 /// ```rust
@@ -303,9 +1265,24 @@ use std::iter::Iterator;
 ///     println!("{}", item);
 /// }
 /// ```
+///
+/// Items can also be taken from the back, for instance to find the last non-zero item.
+///
+/// ```rust
+/// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+///
+/// packed_array.set(2, 5);
+/// packed_array.set(4, 1);
+///
+/// let mut reversed = packed_array.into_iter().rev();
+/// assert_eq!(reversed.len(), 9);
+/// assert_eq!(reversed.position(|item| item != 0), Some(4));
+/// assert_eq!(reversed.len(), 4);
+/// ```
 pub struct PackedIntegerArrayIterator<const BITS: u8, const LEN: usize>
 where [u8; get_array_length(BITS, LEN)]: Sized {
     index: usize,
+    back: usize, // One past the index of the last item that has not been returned from the back
     array: PackedIntegerArray<BITS, LEN>
 }
 
@@ -314,7 +1291,7 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
     type Item = u8;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
-        if (self.index < LEN) {
+        if (self.index < self.back) {
             let val = self.array.get(self.index);
             self.index += 1;
             Some(val)
@@ -322,4 +1299,115 @@ where [u8; get_array_length(BITS, LEN)]: Sized {
             None
         }
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> DoubleEndedIterator for PackedIntegerArrayIterator<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
+        if (self.index < self.back) {
+            self.back -= 1;
+            Some(self.array.get(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> ExactSizeIterator for PackedIntegerArrayIterator<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
+
+impl <const BITS: u8, const LEN: usize> FusedIterator for PackedIntegerArrayIterator<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
+
+/// An iterator that moves over every unpacked value in a borrowed [`PackedIntegerArray`].
+///
+/// Use [`PackedIntegerArray::iter()`] to construct a new instance.
+pub struct Iter<'a, const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    index: usize,
+    back: usize, // One past the index of the last item that has not been returned from the back
+    array: &'a PackedIntegerArray<BITS, LEN>
+}
+
+impl <'a, const BITS: u8, const LEN: usize> Iterator for Iter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if (self.index < self.back) {
+            let val = self.array.get(self.index);
+            self.index += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl <'a, const BITS: u8, const LEN: usize> DoubleEndedIterator for Iter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
+        if (self.index < self.back) {
+            self.back -= 1;
+            Some(self.array.get(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl <'a, const BITS: u8, const LEN: usize> ExactSizeIterator for Iter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
+
+impl <'a, const BITS: u8, const LEN: usize> FusedIterator for Iter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
+
+/// An iterator that moves over the index and unpacked value of every item in a borrowed [`PackedIntegerArray`].
+///
+/// Use [`PackedIntegerArray::indexed_iter()`] to construct a new instance.
+pub struct IndexedIter<'a, const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    index: usize,
+    start_byte: usize, // The index of the byte that contains the start of the current item
+    start_bit: usize, // The first bit on that byte containing the start of the current item
+    array: &'a PackedIntegerArray<BITS, LEN>
+}
+
+impl <'a, const BITS: u8, const LEN: usize> Iterator for IndexedIter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    type Item = (usize, u8);
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if (self.index < LEN) {
+            let item = (self.index, self.array.get_at(self.start_byte, self.start_bit));
+            self.index += 1;
+            self.start_bit += BITS as usize;
+            self.start_byte += self.start_bit / (u8::BITS as usize);
+            self.start_bit %= (u8::BITS as usize);
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = LEN - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl <'a, const BITS: u8, const LEN: usize> ExactSizeIterator for IndexedIter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
+
+impl <'a, const BITS: u8, const LEN: usize> FusedIterator for IndexedIter<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {}
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use crate::{get_array_length, PackedIntegerArray};
+
+/// A [`PackedIntegerArray`] of `LEN` amount of 4 bit items.
+///
+/// Every byte holds two items, the item at an even index in its high nibble and the item after it in its low nibble. Formats that
+/// store the even item in the low nibble instead, such as the light and metadata arrays of classic Minecraft chunks, have every pair
+/// of nibbles swapped compared to this layout.
+///
+/// ```rust
+/// let mut light = pia::NibbleArray::<16>::from_bytes(&[0xF0, 0, 0, 0, 0, 0, 0, 0x0A]);
+/// light.set(1, 0x7);
+///
+/// assert_eq!(light.get(0), 0xF);
+/// assert_eq!(light.get_hi_nibble(0), 0xF);
+/// assert_eq!(light.get_lo_nibble(0), 0x7);
+/// assert_eq!(light.to_string(), "f7 00 00 00 00 00 00 0a");
+/// ```
+pub type NibbleArray<const LEN: usize> = PackedIntegerArray<4, LEN>;
+
+impl <const LEN: usize> PackedIntegerArray<4, LEN>
+where [u8; get_array_length(4, LEN)]: Sized {
+    /// Constructs a new nibble array from the packed `bytes`.
+    ///
+    /// Note: if the length of `bytes` is not the amount of bytes housing `LEN` nibbles the program will panic.
+    #[track_caller]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut array = Self::new();
+        array.content.copy_from_slice(bytes);
+        if LEN % 2 == 1 {
+            // The low nibble of the last byte is padding, which is always kept 0
+            array.content[LEN / 2] &= 0xF0;
+        }
+        array
+    }
+
+    /// Returns the high nibble of the byte at `byte_index`, which is the item at index `2 * byte_index`.
+    ///
+    /// Note: if the byte is outside of the packed content the program will panic.
+    #[track_caller]
+    pub fn get_hi_nibble(&self, byte_index: usize) -> u8 {
+        self.content[byte_index] >> 4
+    }
+
+    /// Returns the low nibble of the byte at `byte_index`, which is the item at index `2 * byte_index + 1`.
+    ///
+    /// Note: if the byte is outside of the packed content the program will panic.
+    #[track_caller]
+    pub fn get_lo_nibble(&self, byte_index: usize) -> u8 {
+        self.content[byte_index] & 0x0F
+    }
+
+    /// Constructs a new nibble array holding `value` as binary coded decimal, one decimal digit per nibble.
+    ///
+    /// The digits are right aligned with the most significant digit first, so the last item holds the ones digit and any items in
+    /// front of the highest digit are 0. This is the packed BCD layout used by most hardware and protocols.
+    ///
+    /// ```rust
+    /// let bcd = pia::NibbleArray::<6>::from_u128_bcd(1995);
+    /// assert_eq!(bcd.as_ref(), [0x00, 0x19, 0x95]);
+    /// ```
+    ///
+    /// Note: if `value` has more decimal digits than `LEN` the program will panic.
+    #[track_caller]
+    pub fn from_u128_bcd(value: u128) -> Self {
+        let mut array = Self::new();
+        let mut rest = value;
+        let mut index = LEN;
+        while rest > 0 {
+            if index == 0 {
+                panic!("{} has more than {} decimal digits", value, LEN);
+            }
+
+            index -= 1;
+            array.set(index, (rest % 10) as u8);
+            rest /= 10;
+        }
+        array
+    }
+
+    /// Returns the number the nibble array holds as binary coded decimal, see [`PackedIntegerArray::from_u128_bcd()`], or `None`
+    /// if a nibble is not a decimal digit or the number does not fit in a `u128`.
+    ///
+    /// ```rust
+    /// let bcd = pia::NibbleArray::<6>::from_bytes(&[0x00, 0x19, 0x95]);
+    /// assert_eq!(bcd.to_u128_bcd(), Some(1995));
+    ///
+    /// let invalid = pia::NibbleArray::<2>::from_bytes(&[0x1A]);
+    /// assert_eq!(invalid.to_u128_bcd(), None);
+    /// ```
+    pub fn to_u128_bcd(&self) -> Option<u128> {
+        let mut value: u128 = 0;
+        for i in 0..LEN {
+            let digit = self.get(i);
+            if digit > 9 {
+                return None;
+            }
+            value = value.checked_mul(10)?.checked_add(digit as u128)?;
+        }
+        Some(value)
+    }
+}
+
+/// Formats the nibbles as a hex dump, two nibbles per byte and the bytes separated by spaces.
+///
+/// ```rust
+/// let mut nibbles = pia::NibbleArray::<5>::new();
+/// nibbles.set(1, 0xC);
+/// nibbles.set(4, 0x3);
+///
+/// assert_eq!(format!("{}", nibbles), "0c 00 3");
+/// ```
+impl <const LEN: usize> fmt::Display for PackedIntegerArray<4, LEN>
+where [u8; get_array_length(4, LEN)]: Sized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..LEN {
+            if i > 0 && i % 2 == 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:x}", self.get(i))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+/// An auxiliary structure over a [`PackedIntegerArray`] holding the cumulative sum of its items, so the sum of any prefix of the
+/// array is a single lookup.
+///
+/// Use [`PackedIntegerArray::build_prefix_sums()`] to construct a new instance. The sums do not borrow the array, so they can be
+/// kept alongside it and updated through [`PrefixSums::set()`].
+///
+/// ```rust
+/// let mut weights = pia::PackedIntegerArray::<4, 5>::new();
+/// weights.set(0, 3);
+/// weights.set(2, 7);
+///
+/// let sums = weights.build_prefix_sums();
+/// assert_eq!(sums.sum(2), 3);
+/// assert_eq!(sums.sum(5), 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSums<const BITS: u8, const LEN: usize> {
+    sums: Vec<u64> // sums[i] is the sum of the items before index i
+}
+
+impl <const BITS: u8, const LEN: usize> PrefixSums<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the sum of the items before `index`.
+    ///
+    /// `index` may be equal to `LEN`, in which case every item is summed.
+    ///
+    /// Note: if `index` is greater than `LEN` the program will panic.
+    #[track_caller]
+    pub fn sum(&self, index: usize) -> u64 {
+        if index > LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        self.sums[index]
+    }
+
+    /// Returns the sum of every item.
+    pub fn total(&self) -> u64 {
+        self.sums[LEN]
+    }
+
+    /// Sets the item at `index` in `array` to `value` and updates the sums to match.
+    ///
+    /// ```rust
+    /// let mut weights = pia::PackedIntegerArray::<4, 5>::new();
+    /// let mut sums = weights.build_prefix_sums();
+    ///
+    /// sums.set(&mut weights, 1, 9);
+    /// assert_eq!(weights.get(1), 9);
+    /// assert_eq!(sums.sum(2), 9);
+    /// ```
+    ///
+    /// Note: `array` has to be the array the sums were built from, otherwise the sums stop matching either array.
+    #[track_caller]
+    pub fn set(&mut self, array: &mut PackedIntegerArray<BITS, LEN>, index: usize, value: u8) {
+        let previous = array.get(index) as u64;
+        array.set(index, value);
+
+        let value = array.get(index) as u64;
+        for sum in &mut self.sums[(index + 1)..] {
+            *sum = *sum - previous + value;
+        }
+    }
+
+    /// Picks the index of an item at random with a probability proportional to the value of the item, or returns `None` if every
+    /// item is 0.
+    ///
+    /// Only available with the `rand` feature.
+    ///
+    /// ```rust
+    /// let mut spawn_weights = pia::PackedIntegerArray::<4, 4>::new();
+    /// spawn_weights.set(1, 15);
+    /// spawn_weights.set(3, 1);
+    ///
+    /// let sums = spawn_weights.build_prefix_sums();
+    /// let index = sums.sample_weighted(&mut rand::thread_rng()).unwrap();
+    /// assert!(index == 1 || index == 3);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn sample_weighted<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<usize> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+
+        // The chosen item is the one whose range of cumulative sums contains the sample
+        let sample = rng.gen_range(0..total);
+        Some(self.sums.partition_point(|&sum| sum <= sample) - 1)
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Builds the [`PrefixSums`] of the array.
+    pub fn build_prefix_sums(&self) -> PrefixSums<BITS, LEN> {
+        let mut sums = Vec::with_capacity(LEN + 1);
+        let mut sum = 0;
+        sums.push(sum);
+        for i in 0..LEN {
+            sum += self.get(i) as u64;
+            sums.push(sum);
+        }
+
+        PrefixSums {
+            sums
+        }
+    }
+}
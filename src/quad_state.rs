@@ -0,0 +1,148 @@
+use std::marker::PhantomData;
+
+use crate::{get_array_length, PackedElement, PackedIntegerArray};
+
+/// A packed array of `LEN` cells of 2 bits each, where every cell holds one of up to four named states of type `S`.
+///
+/// The states are converted to and from bits with their [`PackedElement`] implementation, which has to be at most 2 bits wide. A
+/// new array has every cell in the state stored as 0.
+///
+/// ```rust
+/// use pia::PackedElement;
+///
+/// // Tri-color marking for a garbage collector
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Mark { White, Gray, Black }
+///
+/// impl PackedElement for Mark {
+///     const WIDTH: u8 = 2;
+///
+///     fn to_bits(&self) -> u64 {
+///         *self as u64
+///     }
+///
+///     fn from_bits(bits: u64) -> Self {
+///         match bits {
+///             0 => Mark::White,
+///             1 => Mark::Gray,
+///             _ => Mark::Black
+///         }
+///     }
+/// }
+///
+/// let mut marks = pia::QuadStateArray::<Mark, 10>::new();
+/// marks.set(3, Mark::Gray);
+/// marks.set(7, Mark::Black);
+///
+/// assert_eq!(marks.get(3), Mark::Gray);
+/// assert_eq!(marks.count(Mark::White), 8);
+/// ```
+#[derive(Debug)]
+pub struct QuadStateArray<S: PackedElement, const LEN: usize>
+where [u8; get_array_length(2, LEN)]: Sized {
+    cells: PackedIntegerArray<2, LEN>,
+    state: PhantomData<S>
+}
+
+impl <S: PackedElement, const LEN: usize> QuadStateArray<S, LEN>
+where [u8; get_array_length(2, LEN)]: Sized {
+    /// Constructs a new array with every cell in the state stored as 0.
+    ///
+    /// Note: if the states of `S` are more than 2 bits wide the program will panic.
+    #[track_caller]
+    pub fn new() -> Self {
+        if S::WIDTH > 2 {
+            panic!("quad states can be at most 2 bits wide but the width is {}", S::WIDTH);
+        }
+
+        Self {
+            cells: PackedIntegerArray::new(),
+            state: PhantomData
+        }
+    }
+
+    /// Returns the state of the cell at `index`.
+    ///
+    /// Note: just like a normal array, if a cell outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn get(&self, index: usize) -> S {
+        S::from_bits(self.cells.get(index) as u64)
+    }
+
+    /// Sets the state of the cell at `index` to `state`.
+    ///
+    /// Note: just like a normal array, if a cell outside of the array bounds is set the program will panic.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, state: S) {
+        self.cells.set(index, Self::bits_of(&state));
+    }
+
+    /// Returns the amount of cells in the state `state`.
+    pub fn count(&self, state: S) -> usize {
+        let pattern = Self::bits_of(&state);
+        (0..LEN).filter(|&i| self.cells.get(i) == pattern).count()
+    }
+
+    /// Moves every cell in the state `from` to the state `to` and returns the amount of cells that were moved.
+    ///
+    /// The transition is done a whole byte of 4 cells at a time, which makes this much faster than setting the cells one by one,
+    /// for example when turning every black mark back to white at the end of a garbage collection cycle.
+    ///
+    /// ```rust
+    /// # #[derive(Debug, Clone, Copy, PartialEq)]
+    /// # enum Mark { White, Gray, Black }
+    /// # impl pia::PackedElement for Mark {
+    /// #     const WIDTH: u8 = 2;
+    /// #     fn to_bits(&self) -> u64 { *self as u64 }
+    /// #     fn from_bits(bits: u64) -> Self { [Mark::White, Mark::Gray, Mark::Black][bits as usize] }
+    /// # }
+    /// let mut marks = pia::QuadStateArray::<Mark, 10>::new();
+    /// marks.set(2, Mark::Black);
+    /// marks.set(9, Mark::Black);
+    ///
+    /// assert_eq!(marks.promote_all(Mark::Black, Mark::White), 2);
+    /// assert_eq!(marks.promote_all(Mark::White, Mark::Gray), 10);
+    /// assert_eq!(marks.count(Mark::Gray), 10);
+    /// ```
+    pub fn promote_all(&mut self, from: S, to: S) -> usize {
+        // Repeats a 2 bit pattern over all 4 cells of a byte
+        const SPREAD: u8 = 0b01010101;
+
+        let from = Self::bits_of(&from) * SPREAD;
+        let to = Self::bits_of(&to) * SPREAD;
+        let used_bits = (LEN * 2) % (u8::BITS as usize); // The bits of the last byte that hold cells
+
+        let mut moved = 0;
+        let last = self.cells.content.len().wrapping_sub(1);
+        for (i, byte) in self.cells.content.iter_mut().enumerate() {
+            // The low bit of every cell that equals `from` is set
+            let difference = *byte ^ from;
+            let mut matched = !(difference | (difference >> 1)) & SPREAD;
+            if i == last && used_bits != 0 {
+                // The cells in the padding bits of the last byte are not part of the array
+                matched &= u8::MAX << ((u8::BITS as usize) - used_bits);
+            }
+
+            let cells = matched | (matched << 1);
+            *byte = (*byte & !cells) | (to & cells);
+            moved += matched.count_ones() as usize;
+        }
+        moved
+    }
+
+    /// Returns the packed cells.
+    pub fn as_array(&self) -> &PackedIntegerArray<2, LEN> {
+        &self.cells
+    }
+
+    fn bits_of(state: &S) -> u8 {
+        (state.to_bits() & 0b11) as u8
+    }
+}
+
+impl <S: PackedElement, const LEN: usize> Default for QuadStateArray<S, LEN>
+where [u8; get_array_length(2, LEN)]: Sized {
+    fn default() -> Self {
+        Self::new()
+    }
+}
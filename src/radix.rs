@@ -0,0 +1,111 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+/// A mutable view of a [`PackedIntegerArray`] as a little endian number of `LEN` digits in a given radix, one digit per item.
+///
+/// The item at index 0 is the least significant digit. This allows counters wider than any integer type to be kept in compact
+/// storage.
+///
+/// Use [`PackedIntegerArray::as_radix_number()`] to construct a new instance.
+///
+/// ```rust
+/// let mut packed_array = pia::PackedIntegerArray::<4, 40>::new();
+/// let mut counter = packed_array.as_radix_number(16);
+///
+/// assert!(counter.add_assign_small(u64::MAX));
+/// assert!(counter.add_assign_small(u64::MAX));
+/// assert_eq!(counter.to_u128(), Some(2 * (u64::MAX as u128)));
+/// ```
+pub struct RadixNumber<'a, const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    array: &'a mut PackedIntegerArray<BITS, LEN>,
+    radix: u64
+}
+
+impl <'a, const BITS: u8, const LEN: usize> RadixNumber<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the radix of the number.
+    pub fn radix(&self) -> u64 {
+        self.radix
+    }
+
+    /// Adds `value` to the number, propagating the carry from the least significant digit upwards.
+    ///
+    /// Returns `false` and leaves the number untouched if the sum has more than `LEN` digits.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<4, 3>::new();
+    /// let mut counter = packed_array.as_radix_number(10);
+    ///
+    /// assert!(counter.add_assign_small(995));
+    /// assert!(counter.add_assign_small(4));
+    /// assert!(!counter.add_assign_small(1));
+    /// assert_eq!(counter.to_u128(), Some(999));
+    ///
+    /// assert_eq!(packed_array, [9, 9, 9]);
+    /// ```
+    pub fn add_assign_small(&mut self, value: u64) -> bool {
+        // Check the final carry first so that an overflowing sum does not write anything
+        if self.carry_out(value) != 0 {
+            return false;
+        }
+
+        let radix = self.radix as u128;
+        let mut carry = value as u128;
+        let mut index = 0;
+        while carry != 0 {
+            let sum = self.array.get(index) as u128 + carry;
+            self.array.set(index, (sum % radix) as u8);
+            carry = sum / radix;
+            index += 1;
+        }
+        true
+    }
+
+    /// Returns the number as a `u128`, or `None` if it does not fit in one.
+    pub fn to_u128(&self) -> Option<u128> {
+        let mut value: u128 = 0;
+        for i in (0..LEN).rev() {
+            value = value.checked_mul(self.radix as u128)?.checked_add(self.array.get(i) as u128)?;
+        }
+        Some(value)
+    }
+
+    /// Returns the carry left over after adding `value` to every digit of the number.
+    fn carry_out(&self, value: u64) -> u128 {
+        let radix = self.radix as u128;
+        let mut carry = value as u128;
+        for i in 0..LEN {
+            if carry == 0 {
+                break;
+            }
+            carry = (self.array.get(i) as u128 + carry) / radix;
+        }
+        carry
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns a [`RadixNumber`] view of the array in `radix`, where every item is one digit.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<2, 8>::new();
+    ///
+    /// // Base 4 is the natural radix for 2 bit items
+    /// packed_array.as_radix_number(4).add_assign_small(7);
+    /// assert_eq!(packed_array, [3, 1, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    ///
+    /// Note: if `radix` is less than 2 or has digits that do not fit in `BITS` bits the program will panic.
+    #[track_caller]
+    pub fn as_radix_number(&mut self, radix: u64) -> RadixNumber<'_, BITS, LEN> {
+        if radix < 2 || radix > (1 << BITS) {
+            panic!("radix must be between 2 and {} but the radix is {}", 1u64 << BITS, radix);
+        }
+
+        RadixNumber {
+            array: self,
+            radix
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::ops::Range;
+
+use crate::{get_array_length, PackedIntegerArray};
+
+/// An auxiliary segment tree over a [`PackedIntegerArray`] that answers minimum and maximum queries over any range of items in
+/// logarithmic time.
+///
+/// Use [`PackedIntegerArray::build_range_query()`] to construct a new instance. The tree does not borrow the array, so it can be
+/// kept alongside it and updated through [`RangeQuery::set()`].
+///
+/// ```rust
+/// let mut heights = pia::PackedIntegerArray::<4, 8>::new();
+/// for (i, height) in [3, 9, 4, 12, 1, 7, 7, 2].iter().enumerate() {
+///     heights.set(i, *height);
+/// }
+///
+/// let query = heights.build_range_query();
+/// assert_eq!(query.range_min(0..4), Some(3));
+/// assert_eq!(query.range_max(2..6), Some(12));
+/// assert_eq!(query.range_max(5..5), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeQuery<const BITS: u8, const LEN: usize> {
+    min: Vec<u8>, // Segment tree of minimums, the leaves are stored at LEN..2 * LEN
+    max: Vec<u8> // Segment tree of maximums, laid out the same as min
+}
+
+impl <const BITS: u8, const LEN: usize> RangeQuery<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the smallest item in `range`, or `None` if `range` is empty.
+    ///
+    /// Note: just like slicing a normal array, if `range` is out of bounds the program will panic.
+    #[track_caller]
+    pub fn range_min(&self, range: Range<usize>) -> Option<u8> {
+        Self::query(&self.min, range, u8::min)
+    }
+
+    /// Returns the greatest item in `range`, or `None` if `range` is empty.
+    ///
+    /// Note: just like slicing a normal array, if `range` is out of bounds the program will panic.
+    #[track_caller]
+    pub fn range_max(&self, range: Range<usize>) -> Option<u8> {
+        Self::query(&self.max, range, u8::max)
+    }
+
+    /// Sets the item at `index` in `array` to `value` and updates the tree to match.
+    ///
+    /// ```rust
+    /// let mut heights = pia::PackedIntegerArray::<4, 8>::new();
+    /// let mut query = heights.build_range_query();
+    ///
+    /// query.set(&mut heights, 6, 11);
+    /// assert_eq!(heights.get(6), 11);
+    /// assert_eq!(query.range_max(0..8), Some(11));
+    /// ```
+    ///
+    /// Note: `array` has to be the array the tree was built from, otherwise the tree stops matching either array.
+    #[track_caller]
+    pub fn set(&mut self, array: &mut PackedIntegerArray<BITS, LEN>, index: usize, value: u8) {
+        array.set(index, value);
+
+        let mut node = index + LEN;
+        self.min[node] = array.get(index);
+        self.max[node] = array.get(index);
+        while node > 1 {
+            node /= 2;
+            self.min[node] = u8::min(self.min[node * 2], self.min[node * 2 + 1]);
+            self.max[node] = u8::max(self.max[node * 2], self.max[node * 2 + 1]);
+        }
+    }
+
+    fn query(tree: &[u8], range: Range<usize>, combine: fn(u8, u8) -> u8) -> Option<u8> {
+        if range.start > range.end {
+            panic!("slice index starts at {} but ends at {}", range.start, range.end);
+        }
+        if range.end > LEN {
+            panic!("range end index {} out of range for packed array of length {}", range.end, LEN);
+        }
+
+        let mut result = None;
+        let mut left = range.start + LEN;
+        let mut right = range.end + LEN;
+        while left < right {
+            if left % 2 == 1 {
+                result = Some(result.map_or(tree[left], |value| combine(value, tree[left])));
+                left += 1;
+            }
+            if right % 2 == 1 {
+                right -= 1;
+                result = Some(result.map_or(tree[right], |value| combine(value, tree[right])));
+            }
+            left /= 2;
+            right /= 2;
+        }
+        result
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Builds the [`RangeQuery`] segment tree of the array.
+    pub fn build_range_query(&self) -> RangeQuery<BITS, LEN> {
+        let mut min = vec![0; 2 * LEN];
+        for i in 0..LEN {
+            min[LEN + i] = self.get(i);
+        }
+        let mut max = min.clone();
+        for node in (1..LEN).rev() {
+            min[node] = u8::min(min[node * 2], min[node * 2 + 1]);
+            max[node] = u8::max(max[node * 2], max[node * 2 + 1]);
+        }
+
+        RangeQuery {
+            min,
+            max
+        }
+    }
+}
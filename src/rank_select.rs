@@ -0,0 +1,381 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+const BLOCK_BYTES: usize = 8; // The amount of bytes covered by each cumulative count of a `RankSelectIndex`
+
+impl <const LEN: usize> PackedIntegerArray<1, LEN>
+where [u8; get_array_length(1, LEN)]: Sized {
+    /// Returns the amount of items that are set to 1.
+    ///
+    /// ```rust
+    /// let mut bitmap = pia::PackedIntegerArray::<1, 20>::new();
+    /// bitmap.set(3, 1);
+    /// bitmap.set(17, 1);
+    /// assert_eq!(bitmap.count_ones(), 2);
+    ///
+    /// // The padding bits after the last item are never counted
+    /// bitmap.as_mut()[2] |= 0b0000_1111;
+    /// assert_eq!(bitmap.count_ones(), 2);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        (0..self.content.len()).map(|i| masked_byte(&self.content, LEN, i, true).count_ones() as usize).sum()
+    }
+
+    /// Returns the amount of items that are set to 1 before `index`.
+    ///
+    /// `index` may be equal to `LEN`, in which case every item is counted.
+    ///
+    /// ```rust
+    /// let mut bitmap = pia::PackedIntegerArray::<1, 20>::new();
+    /// bitmap.set(3, 1);
+    /// bitmap.set(17, 1);
+    ///
+    /// assert_eq!(bitmap.rank1(3), 0);
+    /// assert_eq!(bitmap.rank1(4), 1);
+    /// assert_eq!(bitmap.rank1(20), 2);
+    /// ```
+    ///
+    /// Note: if `index` is greater than `LEN` the program will panic.
+    #[track_caller]
+    pub fn rank1(&self, index: usize) -> usize {
+        if index > LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        let whole_bytes = index / (u8::BITS as usize);
+        let count: usize = self.content[..whole_bytes].iter().map(|byte| byte.count_ones() as usize).sum();
+        count + partial_rank(&self.content, index)
+    }
+
+    /// Returns the index of the item that is the `k`th item set to 1, counting from 0, or `None` if fewer items are set.
+    ///
+    /// ```rust
+    /// let mut bitmap = pia::PackedIntegerArray::<1, 20>::new();
+    /// bitmap.set(3, 1);
+    /// bitmap.set(17, 1);
+    ///
+    /// assert_eq!(bitmap.select1(0), Some(3));
+    /// assert_eq!(bitmap.select1(1), Some(17));
+    /// assert_eq!(bitmap.select1(2), None);
+    /// ```
+    pub fn select1(&self, k: usize) -> Option<usize> {
+        select_from(&self.content, LEN, 0, k, true)
+    }
+
+    /// Builds a [`RankSelectIndex`] over the array that answers [`PackedIntegerArray::rank1()`] and
+    /// [`PackedIntegerArray::select1()`] without scanning the whole array.
+    ///
+    /// ```rust
+    /// let mut bitmap = pia::PackedIntegerArray::<1, 1000>::new();
+    /// for i in (0..1000).step_by(7) {
+    ///     bitmap.set(i, 1);
+    /// }
+    ///
+    /// let index = bitmap.build_rank_select_index();
+    /// assert_eq!(index.rank1(700), 100);
+    /// assert_eq!(index.select1(100), Some(700));
+    /// ```
+    pub fn build_rank_select_index(&self) -> RankSelectIndex<'_, LEN> {
+        RankSelectIndex {
+            array: self,
+            blocks: build_blocks(&self.content, LEN)
+        }
+    }
+}
+
+/// An auxiliary index over a 1 bit [`PackedIntegerArray`] that keeps the cumulative amount of set items for every 64 items, so
+/// rank and select queries only have to scan a single block.
+///
+/// Use [`PackedIntegerArray::build_rank_select_index()`] to construct a new instance. The index borrows the array, so it has to be
+/// rebuilt after the array is modified.
+pub struct RankSelectIndex<'a, const LEN: usize>
+where [u8; get_array_length(1, LEN)]: Sized {
+    array: &'a PackedIntegerArray<1, LEN>,
+    blocks: Vec<usize> // blocks[i] is the amount of set items before block i
+}
+
+impl <'a, const LEN: usize> RankSelectIndex<'a, LEN>
+where [u8; get_array_length(1, LEN)]: Sized {
+    /// Returns the amount of items that are set to 1 before `index`, see [`PackedIntegerArray::rank1()`].
+    ///
+    /// Note: if `index` is greater than `LEN` the program will panic.
+    #[track_caller]
+    pub fn rank1(&self, index: usize) -> usize {
+        if index > LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        rank_in(&self.array.content, &self.blocks, index)
+    }
+
+    /// Returns the index of the `k`th item set to 1, see [`PackedIntegerArray::select1()`].
+    pub fn select1(&self, k: usize) -> Option<usize> {
+        select_in(&self.array.content, LEN, &self.blocks, k, true)
+    }
+}
+
+/// Returns the byte at `index` of packed content holding `len` bits, inverted if `ones` is `false` so that zeros can be counted as
+/// ones, and with the padding bits after the last bit always cleared.
+fn masked_byte(content: &[u8], len: usize, index: usize, ones: bool) -> u8 {
+    let byte = if ones { content[index] } else { !content[index] };
+    let used_bits = len - index * (u8::BITS as usize);
+    if used_bits < (u8::BITS as usize) {
+        byte & !(u8::MAX >> used_bits)
+    } else {
+        byte
+    }
+}
+
+/// Returns the cumulative amount of set bits before every block of [`BLOCK_BYTES`] bytes of packed content holding `len` bits,
+/// followed by the total amount.
+fn build_blocks(content: &[u8], len: usize) -> Vec<usize> {
+    let mut blocks = Vec::with_capacity(content.len() / BLOCK_BYTES + 2);
+    let mut count = 0;
+    blocks.push(0);
+    for start in (0..content.len()).step_by(BLOCK_BYTES) {
+        let end = usize::min(start + BLOCK_BYTES, content.len());
+        count += (start..end).map(|i| masked_byte(content, len, i, true).count_ones() as usize).sum::<usize>();
+        blocks.push(count);
+    }
+    blocks
+}
+
+/// Counts the set bits before `index` using the cumulative counts returned by [`build_blocks()`].
+fn rank_in(content: &[u8], blocks: &[usize], index: usize) -> usize {
+    let whole_bytes = index / (u8::BITS as usize);
+    let block = whole_bytes / BLOCK_BYTES;
+    let count: usize = content[(block * BLOCK_BYTES)..whole_bytes].iter().map(|byte| byte.count_ones() as usize).sum();
+    blocks[block] + count + partial_rank(content, index)
+}
+
+/// Finds the `k`th set bit, or the `k`th clear bit if `ones` is `false`, using the cumulative counts returned by
+/// [`build_blocks()`].
+fn select_in(content: &[u8], len: usize, blocks: &[usize], k: usize, ones: bool) -> Option<usize> {
+    let count_before = |block: usize| if ones {
+        blocks[block]
+    } else {
+        usize::min(block * BLOCK_BYTES * (u8::BITS as usize), len) - blocks[block]
+    };
+
+    if k >= count_before(blocks.len() - 1) {
+        return None;
+    }
+
+    // The last block that starts with at most k matching bits before it holds the kth matching bit
+    let (mut low, mut high) = (0, blocks.len());
+    while low < high {
+        let middle = (low + high) / 2;
+        if count_before(middle) <= k {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    let block = low - 1;
+    select_from(content, len, block * BLOCK_BYTES, k - count_before(block), ones)
+}
+
+/// Counts the set bits of the byte containing `index` that come before `index`.
+fn partial_rank(content: &[u8], index: usize) -> usize {
+    let bit = index % (u8::BITS as usize);
+    if bit == 0 {
+        0
+    } else {
+        (content[index / (u8::BITS as usize)] >> ((u8::BITS as usize) - bit)).count_ones() as usize
+    }
+}
+
+/// Finds the `k`th set bit, or the `k`th clear bit if `ones` is `false`, at or after byte `start_byte` of packed content holding
+/// `len` bits.
+fn select_from(content: &[u8], len: usize, start_byte: usize, mut k: usize, ones: bool) -> Option<usize> {
+    for i in start_byte..content.len() {
+        let byte = masked_byte(content, len, i, ones);
+        let matching = byte.count_ones() as usize;
+        if k < matching {
+            let mut byte = byte;
+            for bit in 0..(u8::BITS as usize) {
+                if byte & 0b1000_0000 != 0 {
+                    if k == 0 {
+                        return Some(i * (u8::BITS as usize) + bit);
+                    }
+                    k -= 1;
+                }
+                byte <<= 1;
+            }
+        }
+        k -= matching;
+    }
+    None
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the amount of items equal to `value` before `index`.
+    ///
+    /// `index` may be equal to `LEN`, in which case every item is counted.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(1, 5);
+    /// packed_array.set(4, 5);
+    ///
+    /// assert_eq!(packed_array.rank(5, 4), 1);
+    /// assert_eq!(packed_array.rank(5, 9), 2);
+    /// assert_eq!(packed_array.rank(0, 9), 7);
+    /// ```
+    ///
+    /// Note: if `index` is greater than `LEN` the program will panic.
+    #[track_caller]
+    pub fn rank(&self, value: u8, index: usize) -> usize {
+        if index > LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        (0..index).filter(|&i| self.get(i) == value).count()
+    }
+
+    /// Returns the index of the `k`th item equal to `value`, counting from 0, or `None` if fewer items are equal to `value`.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(1, 5);
+    /// packed_array.set(4, 5);
+    ///
+    /// assert_eq!(packed_array.select(5, 1), Some(4));
+    /// assert_eq!(packed_array.select(5, 2), None);
+    /// ```
+    pub fn select(&self, value: u8, k: usize) -> Option<usize> {
+        (0..LEN).filter(|&i| self.get(i) == value).nth(k)
+    }
+
+    /// Builds a [`ValueIndex`] over the array that answers [`PackedIntegerArray::rank()`] and [`PackedIntegerArray::select()`]
+    /// without scanning the whole array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<2, 100>::new();
+    /// for i in 0..100 {
+    ///     packed_array.set(i, (i % 4) as u8);
+    /// }
+    ///
+    /// let index = packed_array.build_index();
+    /// assert_eq!(index.rank(3, 50), 12);
+    /// assert_eq!(index.select(3, 12), Some(51));
+    /// ```
+    pub fn build_index(&self) -> ValueIndex<'_, BITS, LEN> {
+        let mut values = self.to_vec();
+        let mut levels = Vec::with_capacity(BITS as usize);
+        for level in 0..BITS {
+            let shift = BITS - 1 - level;
+
+            let mut bits = vec![0u8; get_array_length(1, LEN)];
+            for (i, &value) in values.iter().enumerate() {
+                bits[i / (u8::BITS as usize)] |= ((value >> shift) & 1) << ((u8::BITS as usize) - 1 - (i % (u8::BITS as usize)));
+            }
+            let blocks = build_blocks(&bits, LEN);
+            let zeros = LEN - blocks[blocks.len() - 1];
+
+            // The next level holds the items in the same order, except every item with a 0 bit on this level comes first
+            values = values.iter().filter(|&&value| (value >> shift) & 1 == 0)
+                .chain(values.iter().filter(|&&value| (value >> shift) & 1 == 1))
+                .copied()
+                .collect();
+
+            levels.push(WaveletLevel {
+                bits,
+                blocks,
+                zeros
+            });
+        }
+
+        ValueIndex {
+            array: self,
+            levels
+        }
+    }
+}
+
+/// An auxiliary index over a [`PackedIntegerArray`] that answers rank and select queries for every value, stored as a wavelet
+/// matrix.
+///
+/// The matrix has a level for every bit of the items, from the most significant bit down. Every level holds one bit per item along
+/// with cumulative counts like a [`RankSelectIndex`], so the index takes up about `BITS` bits per item on top of the array, and a
+/// query takes a rank or select on each of the `BITS` levels.
+///
+/// Use [`PackedIntegerArray::build_index()`] to construct a new instance. The index borrows the array, so it has to be rebuilt after
+/// the array is modified.
+pub struct ValueIndex<'a, const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    array: &'a PackedIntegerArray<BITS, LEN>,
+    levels: Vec<WaveletLevel> // levels[0] holds the most significant bit of every item
+}
+
+/// A level of a [`ValueIndex`], holding one bit of every item after the items were reordered by the levels above.
+struct WaveletLevel {
+    bits: Vec<u8>,
+    blocks: Vec<usize>, // blocks[i] is the amount of set bits before block i, see `build_blocks()`
+    zeros: usize // The amount of clear bits, which is where the items with a set bit start on the next level
+}
+
+impl <'a, const BITS: u8, const LEN: usize> ValueIndex<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the indexed array.
+    pub fn array(&self) -> &'a PackedIntegerArray<BITS, LEN> {
+        self.array
+    }
+
+    /// Returns the amount of items equal to `value` before `index`, see [`PackedIntegerArray::rank()`].
+    ///
+    /// Note: if `index` is greater than `LEN` the program will panic.
+    #[track_caller]
+    pub fn rank(&self, value: u8, index: usize) -> usize {
+        if index > LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+        if value > PackedIntegerArray::<BITS, LEN>::MAX_VALUE {
+            return 0;
+        }
+
+        let (start, end) = self.narrow(value, index);
+        end - start
+    }
+
+    /// Returns the index of the `k`th item equal to `value`, see [`PackedIntegerArray::select()`].
+    pub fn select(&self, value: u8, k: usize) -> Option<usize> {
+        if value > PackedIntegerArray::<BITS, LEN>::MAX_VALUE {
+            return None;
+        }
+
+        let (start, end) = self.narrow(value, LEN);
+        if k >= end - start {
+            return None;
+        }
+
+        // Follow the kth of those items back up to where it sits in the array
+        let mut position = start + k;
+        for (level, wavelet) in self.levels.iter().enumerate().rev() {
+            position = if (value >> (BITS as usize - 1 - level)) & 1 == 0 {
+                select_in(&wavelet.bits, LEN, &wavelet.blocks, position, false)?
+            } else {
+                select_in(&wavelet.bits, LEN, &wavelet.blocks, position - wavelet.zeros, true)?
+            };
+        }
+        Some(position)
+    }
+
+    /// Follows the items before `index` down every level, returning the range of the last level where the ones equal to `value` end
+    /// up.
+    fn narrow(&self, value: u8, index: usize) -> (usize, usize) {
+        let (mut start, mut end) = (0, index);
+        for (level, wavelet) in self.levels.iter().enumerate() {
+            let start_ones = rank_in(&wavelet.bits, &wavelet.blocks, start);
+            let end_ones = rank_in(&wavelet.bits, &wavelet.blocks, end);
+            if (value >> (BITS as usize - 1 - level)) & 1 == 0 {
+                start -= start_ones;
+                end -= end_ones;
+            } else {
+                start = wavelet.zeros + start_ones;
+                end = wavelet.zeros + end_ones;
+            }
+        }
+        (start, end)
+    }
+}
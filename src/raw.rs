@@ -0,0 +1,78 @@
+use crate::{get_array_length, padding_bits, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new packed array from already packed content, for example content received over the network.
+    ///
+    /// The padding bits at the end of the last byte are not part of any item. They are cleared, so that arrays holding the same
+    /// items always compare and hash equal no matter what the sender left in the padding.
+    ///
+    /// ```rust
+    /// // The last 5 bits of the last byte are padding
+    /// let packed_array = pia::PackedIntegerArray::<3, 9>::from_raw_bytes([0b111_000_00, 0, 0, 0b011_11111]);
+    ///
+    /// assert_eq!(packed_array.get(0), 7);
+    /// assert_eq!(packed_array.get(8), 3);
+    /// assert_eq!(packed_array.into_raw_bytes(), [0b111_000_00, 0, 0, 0b011_00000]);
+    /// ```
+    pub fn from_raw_bytes(bytes: [u8; get_array_length(BITS, LEN)]) -> Self {
+        let mut array = Self {
+            content: bytes
+        };
+        if let Some(last) = array.content.last_mut() {
+            *last &= ((u8::MAX as u16) << padding_bits(BITS, LEN)) as u8;
+        }
+        array
+    }
+
+    /// Consumes the packed array and returns its packed content, the inverse of [`PackedIntegerArray::from_raw_bytes()`].
+    ///
+    /// The padding bits at the end of the last byte are always 0.
+    pub fn into_raw_bytes(self) -> [u8; get_array_length(BITS, LEN)] {
+        self.content
+    }
+
+    /// Views the packed content starting at `ptr` as an array, without copying.
+    ///
+    /// This is meant for arrays that live in memory not managed by Rust, like memory shared between processes or a DMA buffer.
+    ///
+    /// ```rust
+    /// // Stands in for a buffer that was filled by another process
+    /// let buffer = vec![0b111_000_00, 0, 0, 0];
+    ///
+    /// let packed_array = unsafe { pia::PackedIntegerArray::<3, 9>::from_raw(buffer.as_ptr()) };
+    /// assert_eq!(packed_array.get(0), 7);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `ptr` has to be non-null and valid for reads of [`get_array_length(BITS, LEN)`](get_array_length) bytes for the whole
+    /// lifetime `'a`, and the bytes must not be written through any other pointer during that lifetime. Because the array is
+    /// `#[repr(transparent)]` over `u8`s, `ptr` does not need any particular alignment. Check that the writer of the bytes used
+    /// the same [`LAYOUT_VERSION`](crate::LAYOUT_VERSION) and [`Layout`](crate::Layout) before reading them.
+    pub const unsafe fn from_raw<'a>(ptr: *const u8) -> &'a Self {
+        &*(ptr as *const Self)
+    }
+
+    /// Mutably views the packed content starting at `ptr` as an array, without copying.
+    ///
+    /// See [`PackedIntegerArray::from_raw()`].
+    ///
+    /// ```rust
+    /// let mut buffer = vec![0; 4];
+    ///
+    /// let packed_array = unsafe { pia::PackedIntegerArray::<3, 9>::from_raw_mut(buffer.as_mut_ptr()) };
+    /// packed_array.set(0, 7);
+    ///
+    /// assert_eq!(buffer[0], 0b111_000_00);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `ptr` has to be non-null and valid for reads and writes of [`get_array_length(BITS, LEN)`](get_array_length) bytes for the
+    /// whole lifetime `'a`, and the bytes must not be accessed through any other pointer during that lifetime. Just like
+    /// [`PackedIntegerArray::from_raw()`], `ptr` does not need any particular alignment.
+    pub unsafe fn from_raw_mut<'a>(ptr: *mut u8) -> &'a mut Self {
+        &mut *(ptr as *mut Self)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Treats the item at `index` as a saturating reference counter and increments it.
+    ///
+    /// Once a counter reaches the maximum value for `BITS` it saturates and stays there: the amount of references it stands for is
+    /// no longer known, so [`PackedIntegerArray::decr_ref()`] leaves it alone and the entry is never released.
+    ///
+    /// ```rust
+    /// // 2 bit reference counters for a palette of 8 entries
+    /// let mut refs = pia::PackedIntegerArray::<2, 8>::new();
+    ///
+    /// refs.incr_ref(4);
+    /// refs.incr_ref(4);
+    /// assert_eq!(refs.get(4), 2);
+    ///
+    /// refs.incr_ref(4);
+    /// refs.incr_ref(4);
+    /// assert_eq!(refs.get(4), 3);
+    /// ```
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn incr_ref(&mut self, index: usize) {
+        let max = Self::MAX_VALUE;
+        let count = self.get(index);
+        if count < max {
+            self.set(index, count + 1);
+        }
+    }
+
+    /// Treats the item at `index` as a saturating reference counter and decrements it, returning `true` if the counter reached 0
+    /// and the referenced entry can be released.
+    ///
+    /// Saturated counters are never decremented, see [`PackedIntegerArray::incr_ref()`].
+    ///
+    /// ```rust
+    /// let mut refs = pia::PackedIntegerArray::<2, 8>::new();
+    /// refs.incr_ref(4);
+    /// refs.incr_ref(4);
+    ///
+    /// assert!(!refs.decr_ref(4));
+    /// assert!(refs.decr_ref(4));
+    /// ```
+    ///
+    /// Note: if the counter is already 0 the program will panic, because that means a reference was released twice.
+    #[track_caller]
+    pub fn decr_ref(&mut self, index: usize) -> bool {
+        let max = Self::MAX_VALUE;
+        let count = self.get(index);
+        if count == 0 {
+            panic!("reference count underflow: the item at index {} is already 0", index);
+        }
+
+        if count == max {
+            return false;
+        }
+
+        self.set(index, count - 1);
+        count == 1
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Views the array as an array of `NEW_LEN` items of `NEW_BITS` bits each, without copying.
+    ///
+    /// Items are packed one after another starting from the most significant bit, so two shapes with the same total amount of bits
+    /// lay out the exact same bit stream over the exact same bytes. Each new item simply covers a different part of that stream.
+    ///
+    /// ```rust
+    /// let mut nibbles = pia::PackedIntegerArray::<4, 4>::new();
+    /// nibbles.set(0, 0xA);
+    /// nibbles.set(1, 0xB);
+    ///
+    /// // Two 4 bit items make up one 8 bit item
+    /// let bytes = nibbles.reinterpret::<8, 2>();
+    /// assert_eq!(bytes.get(0), 0xAB);
+    /// ```
+    ///
+    /// Note: if `NEW_BITS * NEW_LEN` is not equal to `BITS * LEN` the shapes do not cover the same bits, which is rejected at
+    /// compile time.
+    /// ```compile_fail
+    /// let nibbles = pia::PackedIntegerArray::<4, 4>::new();
+    /// let bytes = nibbles.reinterpret::<8, 3>();
+    /// ```
+    pub fn reinterpret<const NEW_BITS: u8, const NEW_LEN: usize>(&self) -> &PackedIntegerArray<NEW_BITS, NEW_LEN>
+    where [u8; get_array_length(NEW_BITS, NEW_LEN)]: Sized {
+        // Evaluating the constant is what rejects mismatched shapes at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = SameBits::<BITS, LEN, NEW_BITS, NEW_LEN>::CHECK;
+
+        // SAFETY: both types are transparent wrappers around arrays of `u8`s, and the lengths of those arrays are equal because the
+        // total amount of bits is equal.
+        unsafe { &*(self as *const Self as *const PackedIntegerArray<NEW_BITS, NEW_LEN>) }
+    }
+
+    /// Mutably views the array as an array of `NEW_LEN` items of `NEW_BITS` bits each, without copying.
+    ///
+    /// See [`PackedIntegerArray::reinterpret()`].
+    ///
+    /// ```rust
+    /// let mut nibbles = pia::PackedIntegerArray::<4, 4>::new();
+    /// nibbles.reinterpret_mut::<8, 2>().set(1, 0x3C);
+    ///
+    /// assert_eq!(nibbles, [0, 0, 0x3, 0xC]);
+    /// ```
+    ///
+    /// Note: just like [`PackedIntegerArray::reinterpret()`], if `NEW_BITS * NEW_LEN` is not equal to `BITS * LEN` the program
+    /// will not compile.
+    pub fn reinterpret_mut<const NEW_BITS: u8, const NEW_LEN: usize>(&mut self) -> &mut PackedIntegerArray<NEW_BITS, NEW_LEN>
+    where [u8; get_array_length(NEW_BITS, NEW_LEN)]: Sized {
+        // Evaluating the constant is what rejects mismatched shapes at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = SameBits::<BITS, LEN, NEW_BITS, NEW_LEN>::CHECK;
+
+        // SAFETY: see `reinterpret`
+        unsafe { &mut *(self as *mut Self as *mut PackedIntegerArray<NEW_BITS, NEW_LEN>) }
+    }
+}
+
+/// Carries both shapes of a reinterpretation, so that a constant can compare their total amount of bits.
+struct SameBits<const BITS: u8, const LEN: usize, const NEW_BITS: u8, const NEW_LEN: usize>;
+
+impl <const BITS: u8, const LEN: usize, const NEW_BITS: u8, const NEW_LEN: usize> SameBits<BITS, LEN, NEW_BITS, NEW_LEN> {
+    const CHECK: () = if (BITS as usize) * LEN != (NEW_BITS as usize) * NEW_LEN {
+        panic!("can not reinterpret an array as a shape with a different total amount of bits");
+    };
+}
@@ -0,0 +1,54 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the value, start index, and length of the longest run of equal items, or `None` if the array is empty.
+    ///
+    /// When several runs are equally long the first one is returned.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// for i in 3..7 {
+    ///     packed_array.set(i, 5);
+    /// }
+    ///
+    /// assert_eq!(packed_array.longest_run(), Some((5, 3, 4)));
+    /// ```
+    pub fn longest_run(&self) -> Option<(u8, usize, usize)> {
+        if LEN == 0 {
+            return None;
+        }
+
+        let mut value = self.get(0);
+        let mut start = 0;
+        let mut longest = (value, 0, 1);
+        for i in 1..LEN {
+            let item = self.get(i);
+            if item != value {
+                value = item;
+                start = i;
+            } else if i + 1 - start > longest.2 {
+                longest = (value, start, i + 1 - start);
+            }
+        }
+        Some(longest)
+    }
+
+    /// Returns the amount of runs of equal items, which is the amount of items a run length encoding of the array would hold.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(3, 5);
+    /// packed_array.set(4, 5);
+    ///
+    /// // 0 0 0, 5 5, 0 0 0 0
+    /// assert_eq!(packed_array.run_count(), 3);
+    /// ```
+    pub fn run_count(&self) -> usize {
+        if LEN == 0 {
+            return 0;
+        }
+
+        1 + (1..LEN).filter(|&i| self.get(i) != self.get(i - 1)).count()
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{bit_offset_of, get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the index of the first run of items equal to `needle`, or `None` if the items never appear in that order.
+    ///
+    /// When the bits of `needle` fit in a `u64`, the needle is packed once and compared against the bits of the packed content at
+    /// every candidate offset, without unpacking any items.
+    ///
+    /// ```rust
+    /// let mut log = pia::PackedIntegerArray::<3, 12>::new();
+    /// for (i, value) in [1, 4, 4, 7, 2, 4, 7, 2, 0].iter().enumerate() {
+    ///     log.set(i, *value);
+    /// }
+    ///
+    /// assert_eq!(log.find_subsequence(&[4, 7, 2]), Some(2));
+    /// assert_eq!(log.find_subsequence(&[7, 2, 1]), None);
+    /// assert_eq!(log.find_subsequence(&[]), Some(0));
+    /// ```
+    pub fn find_subsequence(&self, needle: &[u8]) -> Option<usize> {
+        if needle.len() > LEN || needle.iter().any(|&value| value > Self::MAX_VALUE) {
+            return None;
+        }
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        let needle_bits = needle.len() * (BITS as usize);
+        if needle_bits > (u64::BITS as usize) {
+            return (0..=(LEN - needle.len())).find(|&start| needle.iter().enumerate().all(|(i, &value)| self.get(start + i) == value));
+        }
+
+        let mask = u64::MAX >> ((u64::BITS as usize) - needle_bits);
+        let pattern = needle.iter().fold(0u64, |pattern, &value| (pattern << BITS) | (value as u64));
+
+        (0..=(LEN - needle.len())).find(|&start| (self.read_bits(bit_offset_of(BITS, start), needle_bits) & mask) == pattern)
+    }
+
+    /// Reads `width` bits, at most 64, starting `bit` bits into the packed content, most significant bit first.
+    fn read_bits(&self, bit: usize, width: usize) -> u64 {
+        let start_byte = bit / (u8::BITS as usize);
+        let start_bit = bit % (u8::BITS as usize);
+        let end_byte = (bit + width + (u8::BITS as usize) - 1) / (u8::BITS as usize);
+
+        // At most 9 bytes are needed for 64 bits that do not start on a byte boundary
+        let bytes = self.content[start_byte..end_byte].iter().fold(0u128, |bytes, &byte| (bytes << u8::BITS) | (byte as u128));
+        (bytes >> ((end_byte - start_byte) * (u8::BITS as usize) - start_bit - width)) as u64
+    }
+}
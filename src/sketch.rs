@@ -0,0 +1,135 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+
+use crate::bloom::{hash_pair, probe_index};
+use crate::{get_array_length, PackedIntegerArray};
+
+/// A counting bloom filter of `LEN` saturating counters of `BITS` bits each, stored in a [`PackedIntegerArray`].
+///
+/// Every item maps to a fixed amount of counters chosen by double hashing. Incrementing an item increments all of its counters and
+/// the estimate for an item is the smallest of its counters, so estimates can be too high because of collisions but are never too
+/// low. Counters stop counting once they reach the maximum value for `BITS`.
+///
+/// ```rust
+/// // 4 bit counters that saturate at 15
+/// let mut sketch = pia::CountingSketch::<4, 512>::new(3);
+///
+/// sketch.increment_hashes("stone");
+/// sketch.increment_hashes("stone");
+/// sketch.increment_hashes("dirt");
+///
+/// assert_eq!(sketch.estimate("stone"), 2);
+/// assert_eq!(sketch.estimate("dirt"), 1);
+/// ```
+pub struct CountingSketch<const BITS: u8, const LEN: usize, S = RandomState>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    counters: PackedIntegerArray<BITS, LEN>,
+    hashes: usize,
+    hasher: S
+}
+
+impl <const BITS: u8, const LEN: usize> CountingSketch<BITS, LEN, RandomState>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new sketch with every counter at 0 that maps every item to `hashes` counters and hashes items with
+    /// [`RandomState`].
+    ///
+    /// Note: an item has to map to at least one counter for its estimate to mean anything, so if `hashes` is 0 the program will
+    /// panic.
+    #[track_caller]
+    pub fn new(hashes: usize) -> Self {
+        Self::with_hasher(hashes, RandomState::new())
+    }
+}
+
+impl <const BITS: u8, const LEN: usize, S: BuildHasher> CountingSketch<BITS, LEN, S>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    const VALID_SHAPE: () = if LEN == 0 {
+        panic!("a counting sketch needs at least one counter");
+    };
+
+    /// Constructs a new sketch with every counter at 0 that maps every item to `hashes` counters and hashes items with `hasher`.
+    ///
+    /// Note: if `hashes` is 0 the program will panic, and a sketch without any counters is rejected at compile time.
+    /// ```should_panic
+    /// let sketch = pia::CountingSketch::<4, 512>::new(0);
+    /// ```
+    /// ```compile_fail
+    /// let sketch = pia::CountingSketch::<4, 0>::new(3);
+    /// ```
+    #[track_caller]
+    pub fn with_hasher(hashes: usize, hasher: S) -> Self {
+        // Evaluating the constant is what rejects empty shapes at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SHAPE;
+
+        if hashes == 0 {
+            panic!("a counting sketch needs at least one hash per item");
+        }
+
+        Self {
+            counters: PackedIntegerArray::new(),
+            hashes,
+            hasher
+        }
+    }
+
+    /// Increments every counter of `item`, leaving counters that are already at the maximum value for `BITS` untouched.
+    ///
+    /// ```rust
+    /// let mut sketch = pia::CountingSketch::<2, 64>::new(2);
+    /// for _ in 0..10 {
+    ///     sketch.increment_hashes(&7);
+    /// }
+    ///
+    /// // 2 bit counters saturate at 3
+    /// assert_eq!(sketch.estimate(&7), 3);
+    /// ```
+    pub fn increment_hashes<T: Hash + ?Sized>(&mut self, item: &T) {
+        let max = PackedIntegerArray::<BITS, LEN>::MAX_VALUE;
+        let (h1, h2) = hash_pair(&self.hasher, item);
+        for i in 0..self.hashes {
+            let index = probe_index(h1, h2, i, LEN);
+            let count = self.counters.get(index);
+            if count < max {
+                self.counters.set(index, count + 1);
+            }
+        }
+    }
+
+    /// Decrements every counter of `item`, leaving counters that are at 0 or saturated at the maximum value for `BITS` untouched.
+    ///
+    /// Saturated counters are left alone because the amount of increments they represent is no longer known.
+    ///
+    /// ```rust
+    /// let mut sketch = pia::CountingSketch::<4, 512>::new(3);
+    /// sketch.increment_hashes("stone");
+    /// sketch.decrement_hashes("stone");
+    ///
+    /// assert_eq!(sketch.estimate("stone"), 0);
+    /// ```
+    pub fn decrement_hashes<T: Hash + ?Sized>(&mut self, item: &T) {
+        let max = PackedIntegerArray::<BITS, LEN>::MAX_VALUE;
+        let (h1, h2) = hash_pair(&self.hasher, item);
+        for i in 0..self.hashes {
+            let index = probe_index(h1, h2, i, LEN);
+            let count = self.counters.get(index);
+            if count > 0 && count < max {
+                self.counters.set(index, count - 1);
+            }
+        }
+    }
+
+    /// Returns an upper bound on the amount of times `item` has been incremented.
+    pub fn estimate<T: Hash + ?Sized>(&self, item: &T) -> u8 {
+        let (h1, h2) = hash_pair(&self.hasher, item);
+        (0..self.hashes)
+            .map(|i| self.counters.get(probe_index(h1, h2, i, LEN)))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Returns the underlying packed array of counters.
+    pub fn counters(&self) -> &PackedIntegerArray<BITS, LEN> {
+        &self.counters
+    }
+}
@@ -0,0 +1,487 @@
+use std::cell::Cell;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{get_array_length, warn_if_truncated, PackedIntegerArray};
+
+/// A mutable view of a range of items in a [`PackedIntegerArray`].
+///
+/// Items of two neighbouring slices can share a byte of the packed content, so every write is done with atomic read-modify-write
+/// operations that only touch the bits of the written item. This makes slices handed out by [`PackedIntegerArray::split_at_mut()`]
+/// safe to mutate from different threads at the same time.
+///
+/// ```rust
+/// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+///
+/// // Item 2 and item 3 share a byte, but writing either one never disturbs the other
+/// let (mut left, mut right) = packed_array.split_at_mut(3);
+/// for i in 0..left.len() {
+///     left.set(i, 1);
+/// }
+/// for i in 0..right.len() {
+///     right.set(i, 6);
+/// }
+///
+/// assert_eq!(packed_array, [1, 1, 1, 6, 6, 6, 6, 6, 6]);
+/// ```
+pub struct PackedSliceMut<'a, const BITS: u8> {
+    bytes: &'a [AtomicU8],
+    start_bit: usize, // The first bit on the first byte containing the start of the first item
+    len: usize
+}
+
+impl <'a, const BITS: u8> PackedSliceMut<'a, BITS> {
+    /// Returns the amount of items in the slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slice holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the packed integer value at `index` in the slice.
+    ///
+    /// Note: just like a normal slice, if an item outside of the slice bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn get(&self, index: usize) -> u8 {
+        if index >= self.len {
+            panic!("index out of bounds: the len is {} but the index is {}", self.len, index);
+        }
+
+        let (start_byte, shift) = self.locate(index);
+        let mut window = (self.bytes[start_byte].load(Ordering::Relaxed) as u16) << (u8::BITS as usize);
+        if shift < (u8::BITS as usize) {
+            window |= self.bytes[start_byte + 1].load(Ordering::Relaxed) as u16;
+        }
+
+        ((window >> shift) & Self::mask()) as u8
+    }
+
+    /// Sets the packed integer value at `index` in the slice to `value`.
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], if an item outside of the slice bounds is set the program will panic, and
+    /// overflowing bits of `value` are truncated.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, value: u8) {
+        warn_if_truncated(value, BITS);
+
+        if index >= self.len {
+            panic!("index out of bounds: the len is {} but the index is {}", self.len, index);
+        }
+
+        let (start_byte, shift) = self.locate(index);
+        let mask = Self::mask() << shift;
+        let window = ((value as u16) << shift) & mask;
+
+        let high = &self.bytes[start_byte];
+        high.fetch_and(!((mask >> (u8::BITS as usize)) as u8), Ordering::Relaxed);
+        high.fetch_or((window >> (u8::BITS as usize)) as u8, Ordering::Relaxed);
+        if shift < (u8::BITS as usize) {
+            let low = &self.bytes[start_byte + 1];
+            low.fetch_and(!(mask as u8), Ordering::Relaxed);
+            low.fetch_or(window as u8, Ordering::Relaxed);
+        }
+    }
+
+    /// Divides the slice into two at `index`, the first holding the items before `index` and the second the rest.
+    ///
+    /// Note: if `index` is greater than the length of the slice the program will panic.
+    #[track_caller]
+    pub fn split_at_mut(&mut self, index: usize) -> (PackedSliceMut<'_, BITS>, PackedSliceMut<'_, BITS>) {
+        if index > self.len {
+            panic!("mid > len");
+        }
+
+        split(self.bytes, self.start_bit, self.len, index)
+    }
+
+    /// Returns the byte of the item at `index` and the shift that aligns the item to the low bits of a 16 bit window starting at
+    /// that byte.
+    fn locate(&self, index: usize) -> (usize, usize) {
+        locate_bit::<BITS>(self.start_bit + index * (BITS as usize))
+    }
+
+    fn mask() -> u16 {
+        (1u16 << BITS) - 1
+    }
+}
+
+impl <'a, const BITS: u8> fmt::Debug for PackedSliceMut<'a, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries((0..self.len).map(|i| self.get(i))).finish()
+    }
+}
+
+/// Splits the `len` items starting at bit `start_bit` of `bytes` in two at `index`.
+fn split<const BITS: u8>(bytes: &[AtomicU8], start_bit: usize, len: usize, index: usize) -> (PackedSliceMut<'_, BITS>, PackedSliceMut<'_, BITS>) {
+    let split_bit = start_bit + index * (BITS as usize);
+    let end_bit = start_bit + len * (BITS as usize);
+
+    // The byte containing the split is shared by both halves when the split is not byte aligned
+    let left_bytes = (split_bit + (u8::BITS as usize) - 1) / (u8::BITS as usize);
+    let right_start = split_bit / (u8::BITS as usize);
+    let right_end = (end_bit + (u8::BITS as usize) - 1) / (u8::BITS as usize);
+
+    (
+        PackedSliceMut {
+            bytes: &bytes[..left_bytes],
+            start_bit,
+            len: index
+        },
+        PackedSliceMut {
+            bytes: &bytes[right_start..right_end],
+            start_bit: split_bit % (u8::BITS as usize),
+            len: len - index
+        }
+    )
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns a [`PackedSliceMut`] over every item of the array.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// let mut slice = packed_array.as_packed_slice_mut();
+    /// slice.set(8, 2);
+    ///
+    /// assert_eq!(packed_array.get(8), 2);
+    /// ```
+    pub fn as_packed_slice_mut(&mut self) -> PackedSliceMut<'_, BITS> {
+        PackedSliceMut {
+            bytes: atomic_bytes(&mut self.content[..]),
+            start_bit: 0,
+            len: LEN
+        }
+    }
+
+    /// Divides the array into two mutable slices at `index`, the first holding the items before `index` and the second the rest.
+    ///
+    /// When `index` does not fall on a byte boundary both slices share the byte containing the split. Writes through either slice
+    /// only ever touch the bits of their own items, see [`PackedSliceMut`].
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// let (mut left, mut right) = packed_array.split_at_mut(3);
+    /// left.set(2, 7);
+    /// right.set(0, 5);
+    ///
+    /// assert_eq!(packed_array.get(2), 7);
+    /// assert_eq!(packed_array.get(3), 5);
+    /// ```
+    ///
+    /// Note: if `index` is greater than `LEN` the program will panic.
+    #[track_caller]
+    pub fn split_at_mut(&mut self, index: usize) -> (PackedSliceMut<'_, BITS>, PackedSliceMut<'_, BITS>) {
+        if index > LEN {
+            panic!("mid > len");
+        }
+
+        split(atomic_bytes(&mut self.content[..]), 0, LEN, index)
+    }
+}
+
+/// An iterator over non-overlapping [`PackedSliceMut`] chunks of a [`PackedIntegerArray`].
+///
+/// Use [`PackedIntegerArray::chunks_mut()`] to construct a new instance.
+pub struct ChunksMut<'a, const BITS: u8> {
+    rest: PackedSliceMut<'a, BITS>,
+    chunk_len: usize
+}
+
+impl <'a, const BITS: u8> Iterator for ChunksMut<'a, BITS> {
+    type Item = PackedSliceMut<'a, BITS>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        // An empty chunk would never shrink the rest, so the iterator would never end
+        if self.chunk_len == 0 {
+            panic!("chunk size must be non-zero");
+        }
+
+        let index = usize::min(self.chunk_len, self.rest.len);
+        let (chunk, rest) = split(self.rest.bytes, self.rest.start_bit, self.rest.len, index);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the smallest amount of items whose bits fill a whole amount of bytes, which is `8 / gcd(BITS, 8)`.
+    ///
+    /// Any run of items that starts on a multiple of this amount starts on a byte boundary, so chunks of this size can be processed
+    /// independently without sharing bytes.
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 64>::aligned_chunk_size(), 8);
+    /// assert_eq!(pia::PackedIntegerArray::<4, 64>::aligned_chunk_size(), 2);
+    /// assert_eq!(pia::PackedIntegerArray::<6, 64>::aligned_chunk_size(), 4);
+    /// assert_eq!(pia::PackedIntegerArray::<8, 64>::aligned_chunk_size(), 1);
+    /// ```
+    pub const fn aligned_chunk_size() -> usize {
+        let mut a = BITS as usize;
+        let mut b = u8::BITS as usize;
+        while b != 0 {
+            let remainder = a % b;
+            a = b;
+            b = remainder;
+        }
+        (u8::BITS as usize) / a
+    }
+
+    /// Rounds `k` up to the nearest multiple of [`PackedIntegerArray::aligned_chunk_size()`], giving the smallest chunk of at least
+    /// `k` items that keeps every chunk byte aligned.
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 64>::chunk_exact_elements(5), 8);
+    /// assert_eq!(pia::PackedIntegerArray::<3, 64>::chunk_exact_elements(17), 24);
+    /// assert_eq!(pia::PackedIntegerArray::<4, 64>::chunk_exact_elements(17), 18);
+    /// ```
+    ///
+    /// A chunk never has to hold more than the whole array, so a `k` greater than `LEN` is rounded up as if it were `LEN`.
+    ///
+    /// ```rust
+    /// assert_eq!(pia::PackedIntegerArray::<3, 20>::chunk_exact_elements(usize::MAX), 24);
+    /// ```
+    pub const fn chunk_exact_elements(k: usize) -> usize {
+        let group = Self::aligned_chunk_size();
+        // Clamping also keeps the rounding from overflowing
+        let k = if k < LEN { k } else { LEN };
+        ((k + group - 1) / group) * group
+    }
+
+    /// Returns an iterator over non-overlapping mutable slices of at least `chunk_len` items each, with the last slice holding
+    /// whatever items are left.
+    ///
+    /// `chunk_len` is rounded up with [`PackedIntegerArray::chunk_exact_elements()`], so that every chunk starts on a byte
+    /// boundary and no two chunks share a byte. The chunks can be handed to different threads.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 20>::new();
+    ///
+    /// // 3 bit items only line up with bytes every 8 items, so chunks of 5 are rounded up to 8
+    /// let chunks: Vec<_> = packed_array.chunks_mut(5).collect();
+    /// assert_eq!(chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(), [8, 8, 4]);
+    ///
+    /// for (i, mut chunk) in chunks.into_iter().enumerate() {
+    ///     for j in 0..chunk.len() {
+    ///         chunk.set(j, i as u8);
+    ///     }
+    /// }
+    /// assert_eq!(packed_array.get(17), 2);
+    ///
+    /// // A chunk length past the end of the array gives a single chunk of the whole array
+    /// assert_eq!(packed_array.chunks_mut(usize::MAX).count(), 1);
+    /// ```
+    ///
+    /// Note: if `chunk_len` is 0 the program will panic.
+    #[track_caller]
+    pub fn chunks_mut(&mut self, chunk_len: usize) -> ChunksMut<'_, BITS> {
+        if chunk_len == 0 {
+            panic!("chunk size must be non-zero");
+        }
+
+        ChunksMut {
+            rest: self.as_packed_slice_mut(),
+            chunk_len: Self::chunk_exact_elements(chunk_len)
+        }
+    }
+}
+
+/// A mutable reference to a single item of a [`PackedIntegerArray`], standing in for the `&mut u8` that can not point into packed
+/// content.
+///
+/// The proxy holds a copy of the item's value. It can be changed through [`PackedElementMut::set()`] or by dereferencing, and is
+/// written back to the array when the proxy is dropped. Unlike a [`PackedSliceMut`] the proxy never leaves the thread that borrowed
+/// the array, so it writes with plain reads and writes of the bytes instead of atomic operations.
+///
+/// Use [`PackedIntegerArray::at_mut()`] to construct a new instance.
+///
+/// ```rust
+/// fn bump(value: &mut u8) {
+///     *value += 1;
+/// }
+///
+/// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+///
+/// *packed_array.at_mut(2) = 5;
+/// bump(&mut packed_array.at_mut(2));
+///
+/// assert_eq!(packed_array.get(2), 6);
+/// ```
+pub struct PackedElementMut<'a, const BITS: u8> {
+    bytes: &'a [Cell<u8>],
+    bit: usize, // The first bit of the referenced item
+    value: u8
+}
+
+impl <'a, const BITS: u8> PackedElementMut<'a, BITS> {
+    /// Returns the current value of the item.
+    pub fn get(&self) -> u8 {
+        self.value
+    }
+
+    /// Sets the value of the item to `value`, writing it to the array right away.
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], overflowing bits of `value` are truncated.
+    pub fn set(&mut self, value: u8) {
+        self.value = value;
+        self.write();
+    }
+
+    fn write(&self) {
+        warn_if_truncated(self.value, BITS);
+
+        let (start_byte, shift) = locate_bit::<BITS>(self.bit);
+        let mask = ((1u16 << BITS) - 1) << shift;
+        let window = ((self.value as u16) << shift) & mask;
+
+        let high = &self.bytes[start_byte];
+        high.set((high.get() & !((mask >> (u8::BITS as usize)) as u8)) | (window >> (u8::BITS as usize)) as u8);
+        if shift < (u8::BITS as usize) {
+            let low = &self.bytes[start_byte + 1];
+            low.set((low.get() & !(mask as u8)) | window as u8);
+        }
+    }
+}
+
+impl <'a, const BITS: u8> Deref for PackedElementMut<'a, BITS> {
+    type Target = u8;
+
+    fn deref(&self) -> &u8 {
+        &self.value
+    }
+}
+
+impl <'a, const BITS: u8> DerefMut for PackedElementMut<'a, BITS> {
+    fn deref_mut(&mut self) -> &mut u8 {
+        &mut self.value
+    }
+}
+
+impl <'a, const BITS: u8> Drop for PackedElementMut<'a, BITS> {
+    fn drop(&mut self) {
+        self.write();
+    }
+}
+
+impl <'a, const BITS: u8> fmt::Debug for PackedElementMut<'a, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+/// Returns the byte containing `bit` and the shift that aligns an item starting at `bit` to the low bits of a 16 bit window
+/// starting at that byte.
+fn locate_bit<const BITS: u8>(bit: usize) -> (usize, usize) {
+    let start_byte = bit / (u8::BITS as usize);
+    let start_bit = bit % (u8::BITS as usize);
+    (start_byte, (u8::BITS as usize * 2) - start_bit - (BITS as usize))
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns a [`PackedElementMut`] proxy for the item at `index`, which can be assigned to or passed along wherever something
+    /// assignable is expected.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// packed_array.at_mut(4).set(3);
+    /// *packed_array.at_mut(5) = 7;
+    ///
+    /// assert_eq!(packed_array, [0, 0, 0, 0, 3, 7, 0, 0, 0]);
+    /// ```
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn at_mut(&mut self, index: usize) -> PackedElementMut<'_, BITS> {
+        if index >= LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        let value = self.get(index);
+        PackedElementMut {
+            bytes: Cell::from_mut(&mut self.content[..]).as_slice_of_cells(),
+            bit: index * (BITS as usize),
+            value
+        }
+    }
+}
+
+/// An iterator over [`PackedElementMut`] proxies for every item of a [`PackedIntegerArray`].
+///
+/// Use [`PackedIntegerArray::iter_mut()`] to construct a new instance.
+pub struct IterMut<'a, const BITS: u8> {
+    bytes: &'a [Cell<u8>],
+    index: usize,
+    len: usize
+}
+
+impl <'a, const BITS: u8> Iterator for IterMut<'a, BITS> {
+    type Item = PackedElementMut<'a, BITS>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let bit = self.index * (BITS as usize);
+        self.index += 1;
+
+        let (start_byte, shift) = locate_bit::<BITS>(bit);
+        let mut window = (self.bytes[start_byte].get() as u16) << (u8::BITS as usize);
+        if shift < (u8::BITS as usize) {
+            window |= self.bytes[start_byte + 1].get() as u16;
+        }
+
+        Some(PackedElementMut {
+            bytes: self.bytes,
+            bit,
+            value: ((window >> shift) & ((1u16 << BITS) - 1)) as u8
+        })
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns an iterator over [`PackedElementMut`] proxies for every item of the array, so items can be modified in place.
+    ///
+    /// Every proxy writes its value back to the array when it is dropped, see [`PackedElementMut`].
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<4, 4>::from_unpacked([1, 2, 3, 4]);
+    ///
+    /// for mut item in packed_array.iter_mut() {
+    ///     item.set(item.get() + 1);
+    /// }
+    /// for mut item in packed_array.iter_mut() {
+    ///     *item *= 2;
+    /// }
+    ///
+    /// assert_eq!(packed_array, [4, 6, 8, 10]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, BITS> {
+        IterMut {
+            bytes: Cell::from_mut(&mut self.content[..]).as_slice_of_cells(),
+            index: 0,
+            len: LEN
+        }
+    }
+}
+
+/// Views exclusively borrowed bytes as atomic bytes so that they can be shared between slices.
+fn atomic_bytes(bytes: &mut [u8]) -> &[AtomicU8] {
+    // SAFETY: `AtomicU8` has the same size and alignment as `u8`, and the exclusive borrow guarantees that nothing else accesses
+    // the bytes non-atomically for as long as the returned slice lives.
+    unsafe { &*(bytes as *mut [u8] as *const [AtomicU8]) }
+}
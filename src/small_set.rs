@@ -0,0 +1,130 @@
+use crate::get_array_length;
+
+/// A set of the values representable with `BITS` bits, stored as a packed bitmask of `2^BITS` bits.
+///
+/// ```rust
+/// // Tracks which entries of a 16 entry palette are in use
+/// let mut used = pia::PackedSmallSet::<4>::new();
+///
+/// used.insert(3);
+/// used.insert(11);
+///
+/// assert!(used.contains(3));
+/// assert!(!used.contains(4));
+/// assert_eq!(used.iter().collect::<Vec<_>>(), [3, 11]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PackedSmallSet<const BITS: u8>
+where [u8; get_array_length(1, 1 << BITS)]: Sized {
+    mask: [u8; get_array_length(1, 1 << BITS)]
+}
+
+impl <const BITS: u8> PackedSmallSet<BITS>
+where [u8; get_array_length(1, 1 << BITS)]: Sized {
+    /// Constructs a new empty set.
+    pub fn new() -> Self {
+        Self {
+            mask: [0; get_array_length(1, 1 << BITS)]
+        }
+    }
+
+    /// Adds `value` to the set, returning `true` if it was not in the set before.
+    ///
+    /// ```rust
+    /// let mut set = pia::PackedSmallSet::<3>::new();
+    /// assert!(set.insert(5));
+    /// assert!(!set.insert(5));
+    /// ```
+    ///
+    /// Note: if `value` can not be represented with `BITS` bits the program will panic.
+    #[track_caller]
+    pub fn insert(&mut self, value: u8) -> bool {
+        let (byte, bit) = Self::position(value);
+        let present = self.mask[byte] & bit != 0;
+        self.mask[byte] |= bit;
+        !present
+    }
+
+    /// Removes `value` from the set, returning `true` if it was in the set before.
+    ///
+    /// ```rust
+    /// let mut set = pia::PackedSmallSet::<3>::new();
+    /// set.insert(5);
+    /// assert!(set.remove(5));
+    /// assert!(!set.contains(5));
+    /// ```
+    ///
+    /// Note: if `value` can not be represented with `BITS` bits the program will panic.
+    #[track_caller]
+    pub fn remove(&mut self, value: u8) -> bool {
+        let (byte, bit) = Self::position(value);
+        let present = self.mask[byte] & bit != 0;
+        self.mask[byte] &= !bit;
+        present
+    }
+
+    /// Returns `true` if `value` is in the set.
+    ///
+    /// ```should_panic
+    /// // 3 bits only go up to 7
+    /// let set = pia::PackedSmallSet::<3>::new();
+    /// set.contains(8);
+    /// ```
+    ///
+    /// Note: if `value` can not be represented with `BITS` bits the program will panic.
+    #[track_caller]
+    pub fn contains(&self, value: u8) -> bool {
+        let (byte, bit) = Self::position(value);
+        self.mask[byte] & bit != 0
+    }
+
+    /// Returns the amount of values in the set.
+    ///
+    /// ```rust
+    /// let mut set = pia::PackedSmallSet::<5>::new();
+    /// set.insert(1);
+    /// set.insert(30);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.mask.iter().map(|byte| byte.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.mask.iter().all(|&byte| byte == 0)
+    }
+
+    /// Removes every value from the set.
+    pub fn clear(&mut self) {
+        self.mask = [0; get_array_length(1, 1 << BITS)];
+    }
+
+    /// Returns an iterator over the values in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..(1usize << BITS)).map(|value| value as u8).filter(move |&value| self.contains(value))
+    }
+
+    /// Returns the packed bitmask, laid out like a 1 bit [`PackedIntegerArray`](crate::PackedIntegerArray) where the item at
+    /// every value is 1 if the value is in the set.
+    pub fn mask(&self) -> &[u8] {
+        &self.mask[..]
+    }
+
+    /// Returns the index of the byte of the mask holding `value` and the bit of `value` within that byte, checking `value` before
+    /// anything is indexed.
+    #[track_caller]
+    fn position(value: u8) -> (usize, u8) {
+        if (value as usize) >= (1usize << BITS) {
+            panic!("value out of bounds: the set holds values up to {} but the value is {}", (1usize << BITS) - 1, value);
+        }
+        ((value as usize) / (u8::BITS as usize), 0b1000_0000 >> ((value as usize) % (u8::BITS as usize)))
+    }
+}
+
+impl <const BITS: u8> Default for PackedSmallSet<BITS>
+where [u8; get_array_length(1, 1 << BITS)]: Sized {
+    fn default() -> Self {
+        Self::new()
+    }
+}
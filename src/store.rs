@@ -0,0 +1,218 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{get_array_length, PackedIntegerArray};
+
+const INDEX_FILE: &str = "index";
+const ARRAY_EXTENSION: &str = "pia";
+
+/// A directory on disk holding many named packed integer arrays of the same shape, along with an index of their names.
+///
+/// Every array is stored as its raw packed bytes in its own file. The index file records the `BITS` and `LEN` of the store so that
+/// a store can not be reopened with a different shape by accident.
+///
+/// Only available with the `store` feature.
+///
+/// ```rust
+/// # let path = std::env::temp_dir().join("pia-doc-store");
+/// # let _ = std::fs::remove_dir_all(&path);
+/// let mut store = pia::PackedStore::<3, 9>::open(&path).unwrap();
+///
+/// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+/// packed_array.set(3, 7);
+/// store.save("chunk_0_0", &packed_array).unwrap();
+///
+/// let store = pia::PackedStore::<3, 9>::open(&path).unwrap();
+/// assert_eq!(store.load("chunk_0_0").unwrap(), Some(packed_array));
+/// ```
+#[derive(Debug)]
+pub struct PackedStore<const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    path: PathBuf,
+    names: BTreeSet<String>
+}
+
+impl <const BITS: u8, const LEN: usize> PackedStore<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Opens the store in the directory at `path`, creating the directory and an empty index if they do not exist yet.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the store was created with a different `BITS` or `LEN`.
+    ///
+    /// ```rust
+    /// # let path = std::env::temp_dir().join("pia-doc-store-open");
+    /// # let _ = std::fs::remove_dir_all(&path);
+    /// pia::PackedStore::<3, 9>::open(&path).unwrap();
+    ///
+    /// // The same directory can not be reopened with a different shape
+    /// assert!(pia::PackedStore::<4, 9>::open(&path).is_err());
+    /// ```
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        fs::create_dir_all(&path)?;
+
+        let index_path = path.join(INDEX_FILE);
+        if !index_path.exists() {
+            let store = Self {
+                path,
+                names: BTreeSet::new()
+            };
+            store.write_index()?;
+            return Ok(store);
+        }
+
+        let index = fs::read_to_string(&index_path)?;
+        let mut lines = index.lines();
+
+        let header = format!("{} {}", BITS, LEN);
+        match lines.next() {
+            Some(line) if line == header => {},
+            Some(line) => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("store has shape {} but {} was expected", line, header))),
+            None => return Err(io::Error::new(io::ErrorKind::InvalidData, "store index is missing its header"))
+        }
+
+        Ok(Self {
+            path,
+            names: lines.map(String::from).collect()
+        })
+    }
+
+    /// Returns the amount of arrays in the store.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if the store holds no arrays.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns `true` if an array named `name` is in the store.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Returns an iterator over the names of every array in the store, in sorted order.
+    ///
+    /// ```rust
+    /// # let path = std::env::temp_dir().join("pia-doc-store-names");
+    /// # let _ = std::fs::remove_dir_all(&path);
+    /// let mut store = pia::PackedStore::<3, 9>::open(&path).unwrap();
+    /// store.save("b", &pia::PackedIntegerArray::new()).unwrap();
+    /// store.save("a", &pia::PackedIntegerArray::new()).unwrap();
+    ///
+    /// assert_eq!(store.names().collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Writes `array` to the store under `name`, replacing any array already saved under that name.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidInput`] if `name` is empty, starts with a `.`, or contains a path separator.
+    pub fn save(&mut self, name: &str, array: &PackedIntegerArray<BITS, LEN>) -> io::Result<()> {
+        Self::check_name(name)?;
+
+        write_atomically(&self.array_path(name), array.as_ref())?;
+        if self.names.insert(name.to_string()) {
+            self.write_index()?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the array saved under `name`, or returns `None` if there is no such array in the store.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the file on disk does not have the packed length of the store.
+    pub fn load(&self, name: &str) -> io::Result<Option<PackedIntegerArray<BITS, LEN>>> {
+        if !self.contains(name) {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(self.array_path(name))?;
+        if bytes.len() != get_array_length(BITS, LEN) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("array {} is {} bytes long but {} were expected", name, bytes.len(), get_array_length(BITS, LEN))));
+        }
+
+        let mut content = [0; get_array_length(BITS, LEN)];
+        content.copy_from_slice(&bytes);
+        Ok(Some(PackedIntegerArray::from_raw_bytes(content)))
+    }
+
+    /// Deletes the array saved under `name`, returning `true` if there was such an array in the store.
+    ///
+    /// ```rust
+    /// # let path = std::env::temp_dir().join("pia-doc-store-remove");
+    /// # let _ = std::fs::remove_dir_all(&path);
+    /// let mut store = pia::PackedStore::<3, 9>::open(&path).unwrap();
+    /// store.save("a", &pia::PackedIntegerArray::new()).unwrap();
+    ///
+    /// assert!(store.remove("a").unwrap());
+    /// assert!(!store.remove("a").unwrap());
+    /// assert_eq!(store.load("a").unwrap(), None);
+    /// ```
+    pub fn remove(&mut self, name: &str) -> io::Result<bool> {
+        if !self.names.remove(name) {
+            return Ok(false);
+        }
+
+        self.write_index()?;
+        fs::remove_file(self.array_path(name))?;
+        Ok(true)
+    }
+
+    /// Returns an iterator that loads every array in the store along with its name, in sorted order.
+    ///
+    /// ```rust
+    /// # let path = std::env::temp_dir().join("pia-doc-store-iter");
+    /// # let _ = std::fs::remove_dir_all(&path);
+    /// let mut store = pia::PackedStore::<3, 9>::open(&path).unwrap();
+    /// for i in 0..3 {
+    ///     let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///     packed_array.set(i, 5);
+    ///     store.save(&format!("chunk_{}", i), &packed_array).unwrap();
+    /// }
+    ///
+    /// for entry in store.iter() {
+    ///     let (name, packed_array) = entry.unwrap();
+    ///     println!("{}: {:?}", name, packed_array);
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<(&str, PackedIntegerArray<BITS, LEN>)>> {
+        self.names().map(move |name| {
+            let array = self.load(name)?.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, name.to_string()))?;
+            Ok((name, array))
+        })
+    }
+
+    fn check_name(name: &str) -> io::Result<()> {
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\', '\n', '\r']) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{:?} is not a valid array name", name)));
+        }
+        Ok(())
+    }
+
+    fn array_path(&self, name: &str) -> PathBuf {
+        self.path.join(format!("{}.{}", name, ARRAY_EXTENSION))
+    }
+
+    fn write_index(&self) -> io::Result<()> {
+        let mut index = format!("{} {}\n", BITS, LEN);
+        for name in &self.names {
+            index.push_str(name);
+            index.push('\n');
+        }
+
+        write_atomically(&self.path.join(INDEX_FILE), index.as_bytes())
+    }
+}
+
+/// Writes `contents` to a temporary file next to `path` first and then renames it to `path`, so that a crash never leaves a half
+/// written file behind.
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut temporary_path = path.as_os_str().to_owned();
+    temporary_path.push(".tmp");
+    fs::write(&temporary_path, contents)?;
+    fs::rename(temporary_path, path)
+}
@@ -0,0 +1,90 @@
+use crate::{get_array_length, PackedIntegerArray};
+
+/// A set of staged writes to a [`PackedIntegerArray`] that are only applied once the whole transaction succeeds.
+///
+/// Passed to the closure given to [`PackedIntegerArray::transaction()`].
+pub struct Transaction<'a, const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    array: &'a PackedIntegerArray<BITS, LEN>,
+    writes: Vec<(usize, u8)>
+}
+
+impl <'a, const BITS: u8, const LEN: usize> Transaction<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns the value at `index` as seen by this transaction, including its own staged writes.
+    ///
+    /// Note: just like [`PackedIntegerArray::get()`], if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn get(&self, index: usize) -> u8 {
+        match self.writes.iter().rev().find(|(i, _)| *i == index) {
+            Some(&(_, value)) => value,
+            None => self.array.get(index)
+        }
+    }
+
+    /// Stages setting the value at `index` to `value`.
+    ///
+    /// Note: just like [`PackedIntegerArray::set()`], if an item outside of the array bounds is set the program will panic, and
+    /// overflowing bits of `value` are truncated.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, value: u8) {
+        if index >= LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        // Truncate right away so that reads within the transaction match what will be written
+        let value = value & PackedIntegerArray::<BITS, LEN>::MAX_VALUE;
+        self.writes.push((index, value));
+    }
+
+    /// Stages setting the value at `index` to 0.
+    #[track_caller]
+    pub fn clear(&mut self, index: usize) {
+        self.set(index, 0);
+    }
+}
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Runs `f` with a [`Transaction`] that stages writes to the array, and applies all of them at once if `f` returns `Ok`.
+    ///
+    /// If `f` returns `Err` or panics none of the staged writes are applied, so updates to several items that have to stay
+    /// consistent with each other can not leave the array half written.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    ///
+    /// // Move a value from one item to another only if the destination is free
+    /// let moved: Result<(), &str> = packed_array.transaction(|txn| {
+    ///     txn.set(2, 5);
+    ///     txn.clear(0);
+    ///     if txn.get(2) != 5 {
+    ///         return Err("destination is occupied");
+    ///     }
+    ///     Ok(())
+    /// });
+    /// assert!(moved.is_ok());
+    /// assert_eq!(packed_array.get(2), 5);
+    ///
+    /// let failed: Result<(), &str> = packed_array.transaction(|txn| {
+    ///     txn.set(2, 1);
+    ///     Err("rolled back")
+    /// });
+    /// assert!(failed.is_err());
+    /// assert_eq!(packed_array.get(2), 5);
+    /// ```
+    pub fn transaction<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where F: FnOnce(&mut Transaction<'_, BITS, LEN>) -> Result<T, E> {
+        let mut transaction = Transaction {
+            array: self,
+            writes: Vec::new()
+        };
+        let result = f(&mut transaction)?;
+
+        let writes = transaction.writes;
+        for (index, value) in writes {
+            self.set(index, value);
+        }
+        Ok(result)
+    }
+}
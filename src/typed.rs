@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+
+use crate::{get_array_length, Error, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new packed integer array from the values of `values` converted with [`Into<u8>`], leaving any items past the
+    /// end of `values` at 0.
+    ///
+    /// ```rust
+    /// #[derive(Clone, Copy)]
+    /// enum Block { Air, Stone, Dirt }
+    ///
+    /// impl From<Block> for u8 {
+    ///     fn from(block: Block) -> u8 {
+    ///         block as u8
+    ///     }
+    /// }
+    ///
+    /// let packed_array = pia::PackedIntegerArray::<2, 4>::from_values(vec![Block::Stone, Block::Dirt, Block::Stone]);
+    /// assert_eq!(packed_array.unwrap(), [1, 2, 1, 0]);
+    /// ```
+    ///
+    /// Returns an error if a converted value does not fit in `BITS` bits, or if `values` yields more than `LEN` values.
+    ///
+    /// ```rust
+    /// let too_wide = pia::PackedIntegerArray::<2, 4>::from_values(vec![1, 4]);
+    /// assert_eq!(too_wide.unwrap_err(), pia::Error::ValueOverflow { value: 4, bits: 2 });
+    ///
+    /// let too_long = pia::PackedIntegerArray::<2, 4>::from_values(vec![1; 6]);
+    /// assert_eq!(too_long.unwrap_err(), pia::Error::LengthMismatch { expected: 4, found: 6 });
+    /// ```
+    pub fn from_values<T: Into<u8>, I: IntoIterator<Item = T>>(values: I) -> Result<Self, Error> {
+        let mut array = Self::new();
+        let mut values = values.into_iter();
+        for (i, value) in values.by_ref().enumerate() {
+            if i >= LEN {
+                return Err(Error::LengthMismatch {
+                    expected: LEN,
+                    found: i + 1 + values.count()
+                });
+            }
+            array.try_set(i, value.into())?;
+        }
+        Ok(array)
+    }
+
+    /// Returns an iterator over every item of the array converted with [`TryFrom<u8>`].
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Block { Air, Stone }
+    ///
+    /// impl TryFrom<u8> for Block {
+    ///     type Error = u8;
+    ///
+    ///     fn try_from(value: u8) -> Result<Block, u8> {
+    ///         match value {
+    ///             0 => Ok(Block::Air),
+    ///             1 => Ok(Block::Stone),
+    ///             other => Err(other)
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut packed_array = pia::PackedIntegerArray::<2, 3>::new();
+    /// packed_array.set(1, 1);
+    /// packed_array.set(2, 3);
+    ///
+    /// let blocks: Vec<_> = packed_array.iter_as::<Block>().collect();
+    /// assert_eq!(blocks, [Ok(Block::Air), Ok(Block::Stone), Err(3)]);
+    /// ```
+    pub fn iter_as<T: TryFrom<u8>>(&self) -> impl Iterator<Item = Result<T, T::Error>> + '_ {
+        (0..LEN).map(move |i| T::try_from(self.get(i)))
+    }
+}
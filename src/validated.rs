@@ -0,0 +1,122 @@
+use crate::{get_array_length, Error, PackedIntegerArray};
+
+/// A [`PackedIntegerArray`] that consults a validator on every write, so invariants on the items are enforced in a single place.
+///
+/// ```rust
+/// // The border items of the row have to stay 0
+/// fn border_is_empty(index: usize, value: u8) -> bool {
+///     (index != 0 && index != 8) || value == 0
+/// }
+///
+/// let mut row = pia::ValidatedPackedArray::<3, 9>::new(border_is_empty);
+///
+/// assert!(row.set(4, 6).is_ok());
+/// assert!(row.set(8, 6).is_err());
+/// assert_eq!(row.get(8), 0);
+/// ```
+#[derive(Debug)]
+pub struct ValidatedPackedArray<const BITS: u8, const LEN: usize>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    array: PackedIntegerArray<BITS, LEN>,
+    validator: fn(usize, u8) -> bool
+}
+
+impl <const BITS: u8, const LEN: usize> ValidatedPackedArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new array of zeroes guarded by `validator`.
+    ///
+    /// Note: the zeroed items are not checked against `validator`; use [`ValidatedPackedArray::from_array()`] to start from
+    /// content that is validated up front.
+    pub fn new(validator: fn(usize, u8) -> bool) -> Self {
+        Self {
+            array: PackedIntegerArray::new(),
+            validator
+        }
+    }
+
+    /// Wraps `array` after checking every item against `validator`, returning the first rejected item as an error.
+    ///
+    /// ```rust
+    /// let mut packed_array = pia::PackedIntegerArray::<3, 9>::new();
+    /// packed_array.set(0, 1);
+    ///
+    /// let result = pia::ValidatedPackedArray::from_array(packed_array, |_, value| value == 0);
+    /// assert_eq!(result.unwrap_err(), pia::Error::Rejected { index: 0, value: 1 });
+    /// ```
+    pub fn from_array(array: PackedIntegerArray<BITS, LEN>, validator: fn(usize, u8) -> bool) -> Result<Self, Error> {
+        for index in 0..LEN {
+            let value = array.get(index);
+            if !validator(index, value) {
+                return Err(Error::Rejected {
+                    index,
+                    value
+                });
+            }
+        }
+
+        Ok(Self {
+            array,
+            validator
+        })
+    }
+
+    /// Returns the value at `index`, see [`PackedIntegerArray::get()`].
+    #[track_caller]
+    pub fn get(&self, index: usize) -> u8 {
+        self.array.get(index)
+    }
+
+    /// Sets the value at `index` to `value` if the validator accepts it, otherwise leaves the array untouched and returns an error.
+    ///
+    /// Values that do not fit in `BITS` bits are rejected before the validator is consulted, so the validator always sees the
+    /// value that is stored.
+    ///
+    /// ```rust
+    /// let mut row = pia::ValidatedPackedArray::<3, 9>::new(|_, value| value != 0);
+    ///
+    /// // 8 would be truncated to 0, which the validator forbids
+    /// assert_eq!(row.set(2, 8), Err(pia::Error::ValueOverflow { value: 8, bits: 3 }));
+    /// assert_eq!(row.set(2, 0), Err(pia::Error::Rejected { index: 2, value: 0 }));
+    /// ```
+    ///
+    /// The index is checked before the validator is consulted as well, so the validator never sees an index outside of the array
+    /// bounds.
+    ///
+    /// ```rust
+    /// let mut row = pia::ValidatedPackedArray::<3, 9>::new(|_, _| true);
+    /// assert_eq!(row.set(9, 1), Err(pia::Error::IndexOutOfBounds { index: 9, len: 9 }));
+    /// ```
+    pub fn set(&mut self, index: usize, value: u8) -> Result<(), Error> {
+        if index >= LEN {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: LEN
+            });
+        }
+        if value > PackedIntegerArray::<BITS, LEN>::MAX_VALUE {
+            return Err(Error::ValueOverflow {
+                value: value as u64,
+                bits: BITS
+            });
+        }
+        if !(self.validator)(index, value) {
+            return Err(Error::Rejected {
+                index,
+                value
+            });
+        }
+
+        self.array.set(index, value);
+        Ok(())
+    }
+
+    /// Returns the wrapped array.
+    pub fn as_array(&self) -> &PackedIntegerArray<BITS, LEN> {
+        &self.array
+    }
+
+    /// Consumes the wrapper and returns the wrapped array.
+    pub fn into_inner(self) -> PackedIntegerArray<BITS, LEN> {
+        self.array
+    }
+}
@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::{get_array_length, warn_if_truncated};
+
+/// A bit packed array of `LEN` amount of items each of `BITS` amount of bits that lives behind a raw pointer and is only ever
+/// accessed with volatile reads and writes.
+///
+/// Items are packed the same way as in a [`PackedIntegerArray`](crate::PackedIntegerArray). This makes it possible to model bit
+/// packed hardware register banks or framebuffers, where the compiler must not elide, merge or reorder accesses. Reads and writes
+/// only touch the bytes that house the accessed item.
+///
+/// ```rust
+/// // Stands in for a bank of memory mapped registers
+/// let mut registers = [0u8; 4];
+///
+/// let mut bank = unsafe { pia::VolatilePackedArray::<3, 9>::new(registers.as_mut_ptr()) };
+/// bank.set(2, 5);
+/// assert_eq!(bank.get(2), 5);
+///
+/// assert_eq!(registers, [0b000_000_10, 0b1_0000000, 0, 0]);
+/// ```
+#[derive(Debug)]
+pub struct VolatilePackedArray<'a, const BITS: u8, const LEN: usize> {
+    ptr: *mut u8,
+    memory: PhantomData<&'a mut [u8]>
+}
+
+impl <'a, const BITS: u8, const LEN: usize> VolatilePackedArray<'a, BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Constructs a new array over the packed content starting at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` has to be non-null and valid for volatile reads and writes of [`get_array_length(BITS, LEN)`](get_array_length)
+    /// bytes for the whole lifetime `'a`.
+    pub unsafe fn new(ptr: *mut u8) -> Self {
+        Self {
+            ptr,
+            memory: PhantomData
+        }
+    }
+
+    /// Returns the packed integer value at `index` in the array, see [`PackedIntegerArray::get()`](crate::PackedIntegerArray::get).
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is accessed the program will panic.
+    #[track_caller]
+    pub fn get(&self, index: usize) -> u8 {
+        if index >= LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        let (start_byte, shift) = Self::locate(index);
+        let mut window = (self.read(start_byte) as u16) << (u8::BITS as usize);
+        if shift < (u8::BITS as usize) {
+            window |= self.read(start_byte + 1) as u16;
+        }
+
+        ((window >> shift) & Self::mask()) as u8
+    }
+
+    /// Sets the packed integer value at `index` in the array to `value`, see
+    /// [`PackedIntegerArray::set()`](crate::PackedIntegerArray::set).
+    ///
+    /// Every byte housing the item is read once and written once.
+    ///
+    /// Note: just like a normal array, if an item outside of the array bounds is set the program will panic, and overflowing bits
+    /// of `value` are truncated.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, value: u8) {
+        warn_if_truncated(value, BITS);
+
+        if index >= LEN {
+            panic!("index out of bounds: the len is {} but the index is {}", LEN, index);
+        }
+
+        let (start_byte, shift) = Self::locate(index);
+        let mask = Self::mask() << shift;
+        let window = ((value as u16) << shift) & mask;
+
+        let high = (self.read(start_byte) & !((mask >> (u8::BITS as usize)) as u8)) | ((window >> (u8::BITS as usize)) as u8);
+        self.write(start_byte, high);
+        if shift < (u8::BITS as usize) {
+            let low = (self.read(start_byte + 1) & !(mask as u8)) | (window as u8);
+            self.write(start_byte + 1, low);
+        }
+    }
+
+    /// Returns the amount of items in the array.
+    pub const fn len(&self) -> usize {
+        LEN
+    }
+
+    /// Returns `true` if the array holds no items.
+    pub const fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+
+    /// Returns the pointer to the start of the packed content.
+    pub const fn as_ptr(&self) -> *mut u8 {
+        self.ptr
+    }
+
+    fn read(&self, byte: usize) -> u8 {
+        // SAFETY: `byte` is within the packed content, which `new` requires to be valid for volatile reads
+        unsafe { ptr::read_volatile(self.ptr.add(byte)) }
+    }
+
+    fn write(&mut self, byte: usize, value: u8) {
+        // SAFETY: `byte` is within the packed content, which `new` requires to be valid for volatile writes
+        unsafe { ptr::write_volatile(self.ptr.add(byte), value) }
+    }
+
+    /// Returns the byte of the item at `index` and the shift that aligns the item to the low bits of a 16 bit window starting at
+    /// that byte.
+    fn locate(index: usize) -> (usize, usize) {
+        let bit = index * (BITS as usize);
+        let start_byte = bit / (u8::BITS as usize);
+        let start_bit = bit % (u8::BITS as usize);
+        (start_byte, (u8::BITS as usize * 2) - start_bit - (BITS as usize))
+    }
+
+    fn mask() -> u16 {
+        (1u16 << BITS) - 1
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use crate::{get_array_length, PackedIntegerArray};
+
+impl <const BITS: u8, const LEN: usize> PackedIntegerArray<BITS, LEN>
+where [u8; get_array_length(BITS, LEN)]: Sized {
+    /// Returns an iterator over the sums of every window of `k` consecutive items, just like the windows of
+    /// [`slice::windows()`].
+    ///
+    /// The sum is kept rolling, so every item is only read twice no matter how large `k` is.
+    ///
+    /// ```rust
+    /// let mut readings = pia::PackedIntegerArray::<4, 6>::new();
+    /// for (i, value) in [3, 1, 4, 1, 5, 9].iter().enumerate() {
+    ///     readings.set(i, *value);
+    /// }
+    ///
+    /// let sums: Vec<_> = readings.window_sums(3).collect();
+    /// assert_eq!(sums, [8, 6, 10, 15]);
+    /// ```
+    ///
+    /// Note: if `k` is 0 the program will panic.
+    #[track_caller]
+    pub fn window_sums(&self, k: usize) -> impl Iterator<Item = usize> + '_ {
+        if k == 0 {
+            panic!("window size must be non-zero");
+        }
+
+        let mut sum: usize = (0..usize::min(k, LEN)).map(|i| self.get(i) as usize).sum();
+        let mut start = 0;
+        std::iter::from_fn(move || {
+            if start + k > LEN {
+                return None;
+            }
+
+            let current = sum;
+            if start + k < LEN {
+                sum = sum + (self.get(start + k) as usize) - (self.get(start) as usize);
+            }
+            start += 1;
+            Some(current)
+        })
+    }
+
+    /// Returns an iterator over the greatest item of every window of `k` consecutive items, just like the windows of
+    /// [`slice::windows()`].
+    ///
+    /// The candidates for the greatest item are kept in a monotonic queue, so every item is only read once no matter how large `k`
+    /// is.
+    ///
+    /// ```rust
+    /// let mut readings = pia::PackedIntegerArray::<4, 6>::new();
+    /// for (i, value) in [3, 1, 4, 1, 5, 9].iter().enumerate() {
+    ///     readings.set(i, *value);
+    /// }
+    ///
+    /// let maxima: Vec<_> = readings.window_max(3).collect();
+    /// assert_eq!(maxima, [4, 4, 5, 9]);
+    /// ```
+    ///
+    /// Note: if `k` is 0 the program will panic.
+    #[track_caller]
+    pub fn window_max(&self, k: usize) -> impl Iterator<Item = u8> + '_ {
+        if k == 0 {
+            panic!("window size must be non-zero");
+        }
+
+        // The indices of the items that can still become the greatest of a window, with decreasing values
+        let mut candidates: VecDeque<(usize, u8)> = VecDeque::new();
+        let mut next = 0;
+        std::iter::from_fn(move || {
+            // Read items until the window ending before `next` is complete, which is one item after the first window
+            loop {
+                if next >= LEN {
+                    return None;
+                }
+
+                let value = self.get(next);
+                while matches!(candidates.back(), Some(&(_, candidate)) if candidate <= value) {
+                    candidates.pop_back();
+                }
+                candidates.push_back((next, value));
+                next += 1;
+
+                if next >= k {
+                    break;
+                }
+            }
+
+            while matches!(candidates.front(), Some(&(index, _)) if index + k < next) {
+                candidates.pop_front();
+            }
+            candidates.front().map(|&(_, value)| value)
+        })
+    }
+}